@@ -0,0 +1,192 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::{Cursor, ParseError, Point, Result};
+
+/// A dense, rectangular grid stored row-major.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Grid<char> {
+    /// Parses one row per line. Blank lines are ignored, but every row must
+    /// have the same width.
+    pub fn parse<'a>(input: impl Into<Cursor<'a>>) -> Result<Grid<char>> {
+        Grid::parse_with(input, "a character", Some)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Like [`Grid::parse`], converting each character with `cell`. A character
+    /// that `cell` rejects is reported as not being `expected`.
+    pub fn parse_with<'a>(
+        input: impl Into<Cursor<'a>>,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>> {
+        let mut cells = vec![];
+        let mut rows = 0;
+        let mut cols = None;
+        for mut line in input.into().lines() {
+            let mut width = 0;
+            while let Some(c) = line.peek() {
+                cells.push(cell(c).ok_or_else(|| line.error(expected))?);
+                line.bump();
+                width += 1;
+            }
+
+            let cols = *cols.get_or_insert(width);
+            if width != cols {
+                let (line, column) = line.location();
+                return Err(ParseError {
+                    line,
+                    column,
+                    expected: format!("a row of {} cells", cols),
+                    found: format!("{} cells", width),
+                }
+                .into());
+            }
+            rows += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            rows,
+            cols: cols.unwrap_or(0),
+        })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let cols = rows.first().map_or(0, |r| r.len());
+        if let Some((r, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != cols) {
+            return Err(format!("row {} has {} cells, expected {}", r, row.len(), cols).into());
+        }
+
+        let height = rows.len();
+        Ok(Grid {
+            cells: rows.into_iter().flatten().collect(),
+            rows: height,
+            cols,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        p.row >= 0 && p.col >= 0 && (p.row as usize) < self.rows && (p.col as usize) < self.cols
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(move |i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |r| (0..cols).map(move |c| Point::from((r, c))))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The orthogonal neighbors of `p` that lie inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().filter(|n| self.in_bounds(*n))
+    }
+
+    /// The orthogonal and diagonal neighbors of `p` that lie inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().filter(|n| self.in_bounds(*n))
+    }
+
+    /// Positions of all cells matching `pred`, row by row.
+    pub fn positions<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter().filter(move |(_, v)| pred(v)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        if self.in_bounds(p) {
+            Some(p.row as usize * self.cols + p.col as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Grid<T> {
+        Grid {
+            cells: vec![fill; rows * cols],
+            rows,
+            cols,
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The first position (row by row) holding `value`, e.g. a `'^'` or `'S'` marker.
+    pub fn find(&self, value: &T) -> Option<Point> {
+        self.positions(|v| v == value).next()
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{} is outside a {}x{} grid", p, self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.index_of(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{} is outside a {}x{} grid", p, self.rows, self.cols),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for r in 0..self.rows {
+            for cell in self.row(r) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
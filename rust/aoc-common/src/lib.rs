@@ -0,0 +1,21 @@
+//! Shared building blocks for the daily solutions.
+
+mod answer;
+mod direction;
+mod grid;
+mod params;
+pub mod parse;
+mod point;
+mod solution;
+mod verbose;
+
+pub use answer::Answer;
+pub use direction::Direction;
+pub use grid::Grid;
+pub use params::{Param, Params};
+pub use parse::{Cursor, ParseError};
+pub use point::Point;
+pub use solution::{read, run, Puzzle, Solution};
+pub use verbose::{set_verbose, verbose};
+
+pub type Result<T> = ::std::result::Result<T, Box<dyn ::std::error::Error>>;
@@ -0,0 +1,125 @@
+//! Tunable puzzle constants, such as the size of a grid or the number of
+//! rounds to simulate. Each day declares its parameters with defaults that
+//! suit the real input, and the runner can override them, e.g. for a sample.
+
+use std::collections::BTreeMap;
+
+use crate::Result;
+
+/// A declared parameter, its default value and the values it can take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    /// The smallest value the solution can work with.
+    pub min: i64,
+    /// The largest value the solution can work with.
+    pub max: i64,
+    pub help: &'static str,
+}
+
+impl Param {
+    /// The valid values, e.g. `1..` or `0..=9`.
+    pub fn range(&self) -> String {
+        if self.max == i64::MAX {
+            format!("{}..", self.min)
+        } else {
+            format!("{}..={}", self.min, self.max)
+        }
+    }
+}
+
+/// The value of each declared parameter: its default unless overridden.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<&'static str, (Param, i64)>,
+}
+
+impl Params {
+    /// The defaults of `declared`.
+    pub fn new(declared: &'static [Param]) -> Params {
+        Params {
+            values: declared.iter().map(|p| (p.name, (*p, p.default))).collect(),
+        }
+    }
+
+    /// Overrides a declared parameter, if `value` is in its range.
+    pub fn set(&mut self, name: &str, value: i64) -> Result<()> {
+        if let Some((param, v)) = self.values.get_mut(name) {
+            if value < param.min || value > param.max {
+                return Err(format!(
+                    "parameter `{}` should be in {}, not {}",
+                    name,
+                    param.range(),
+                    value
+                )
+                .into());
+            }
+            *v = value;
+            return Ok(());
+        }
+
+        if self.values.is_empty() {
+            Err(format!("unknown parameter `{}`: this day has none", name).into())
+        } else {
+            let names: Vec<_> = self.values.keys().copied().collect();
+            Err(format!(
+                "unknown parameter `{}`, expected one of {}",
+                name,
+                names.join(", ")
+            )
+            .into())
+        }
+    }
+
+    /// Parses and applies an override written as `name=value`.
+    pub fn set_from_str(&mut self, assignment: &str) -> Result<()> {
+        let (name, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("expected name=value, found `{}`", assignment))?;
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("parameter `{}` should be an integer", name.trim()))?;
+        self.set(name.trim(), value)
+    }
+
+    /// The value of `name`, converted to the type the solution works in.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T> {
+        let (_, value) = *self
+            .values
+            .get(name)
+            .ok_or_else(|| format!("parameter `{}` is not declared", name))?;
+        T::try_from(value)
+            .map_err(|_| format!("parameter `{}` is out of range: {}", name, value).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[Param] = &[Param {
+        name: "width",
+        default: 101,
+        min: 1,
+        max: i64::MAX,
+        help: "Width of the grid",
+    }];
+
+    #[test]
+    fn values_must_be_in_range() {
+        let mut params = Params::new(DECLARED);
+        params.set_from_str("width = 11").unwrap();
+        assert_eq!(params.get::<i32>("width").unwrap(), 11);
+        assert_eq!(
+            params.set_from_str("width=0").unwrap_err().to_string(),
+            "parameter `width` should be in 1.., not 0"
+        );
+        assert_eq!(params.get::<i32>("width").unwrap(), 11);
+        assert_eq!(
+            params.set("height", 7).unwrap_err().to_string(),
+            "unknown parameter `height`, expected one of width"
+        );
+    }
+}
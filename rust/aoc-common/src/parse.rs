@@ -0,0 +1,322 @@
+//! Position-aware parsing of puzzle input. A [`Cursor`] walks the text and,
+//! when something unexpected turns up, reports a [`ParseError`] that says
+//! where it happened and what was expected there.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A malformed input, located by 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A read position in some input text.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    text: &'a str,
+    pos: usize,
+    first_line: usize,
+}
+
+/// The non-blank lines of `input`, each as a cursor that knows its line number.
+pub fn lines(input: &str) -> impl Iterator<Item = Cursor<'_>> {
+    Cursor::new(input).lines()
+}
+
+/// Splits `input` into blocks separated by blank lines, each as a cursor
+/// that knows the line number it starts on.
+pub fn blocks(input: &str) -> Vec<Cursor<'_>> {
+    let mut blocks = vec![];
+    let mut start = None;
+    let mut offset = 0;
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        match (line.trim().is_empty(), start) {
+            (false, None) => start = Some((offset, i + 1)),
+            (true, Some((from, n))) => {
+                blocks.push(Cursor::at_line(input[from..offset].trim_end(), n));
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+    if let Some((from, n)) = start {
+        blocks.push(Cursor::at_line(input[from..].trim_end(), n));
+    }
+    blocks
+}
+
+/// Exactly `N` blocks separated by blank lines, such as the rules and the
+/// updates of a puzzle input.
+pub fn sections<const N: usize>(input: &str) -> Result<[Cursor<'_>; N], ParseError> {
+    let blocks = blocks(input);
+    if let Some(extra) = blocks.get(N) {
+        return Err(extra.error("end of input"));
+    }
+
+    blocks.try_into().map_err(|_| {
+        let mut end = Cursor::new(input);
+        end.pos = input.len();
+        end.error(format!("{} sections separated by blank lines", N))
+    })
+}
+
+impl<'a> From<&'a str> for Cursor<'a> {
+    fn from(text: &'a str) -> Self {
+        Cursor::new(text)
+    }
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Cursor::at_line(text, 1)
+    }
+
+    /// A cursor over `text`, which starts on line `line` of the whole input.
+    pub fn at_line(text: &'a str, line: usize) -> Self {
+        Cursor {
+            text,
+            pos: 0,
+            first_line: line,
+        }
+    }
+
+    /// The unread text.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// The 1-based line and column of the read position.
+    pub fn location(&self) -> (usize, usize) {
+        let before = &self.text[..self.pos];
+        let line = self.first_line + before.matches('\n').count();
+        let column = before.rfind('\n').map_or(before, |i| &before[i + 1..]);
+        (line, column.chars().count() + 1)
+    }
+
+    /// An error at the read position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let (line, column) = self.location();
+        let found = match self.peek() {
+            None => "end of input".to_string(),
+            Some('\n') => "end of line".to_string(),
+            Some(c) => format!("{:?}", c),
+        };
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// Consumes and returns the next character.
+    pub fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Consumes `token` if the unread text starts with it.
+    pub fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, token: &str) -> Result<(), ParseError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(format!("{:?}", token)))
+        }
+    }
+
+    /// Skips spaces and tabs, but not line breaks.
+    pub fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    }
+
+    /// Consumes the longest prefix whose characters all match `pred`.
+    pub fn take_while(&mut self, mut pred: impl FnMut(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// A run of letters and digits, such as a wire or computer name.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        match self.take_while(|c| c.is_ascii_alphanumeric()) {
+            "" => Err(self.error("a name")),
+            word => Ok(word),
+        }
+    }
+
+    /// A decimal number with an optional sign, converted to any integer type.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.clone();
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return Err(self.error("a number"));
+        }
+
+        let token = &rest[..sign + digits];
+        self.pos += token.len();
+        token
+            .parse()
+            .map_err(|_| start.error(format!("a number that fits, not {}", token)))
+    }
+
+    /// A single decimal digit.
+    pub fn digit(&mut self) -> Result<u32, ParseError> {
+        match self.peek().and_then(|c| c.to_digit(10)) {
+            Some(d) => {
+                self.pos += 1;
+                Ok(d)
+            }
+            None => Err(self.error("a digit")),
+        }
+    }
+
+    /// One or more items separated by `sep`.
+    pub fn separated<T>(
+        &mut self,
+        sep: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        while self.eat(sep) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Succeeds only if nothing but trailing whitespace is left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        let rest = self.rest();
+        if rest.trim_end().is_empty() {
+            self.pos = self.text.len();
+            Ok(())
+        } else {
+            self.pos += rest.len() - rest.trim_start_matches([' ', '\t']).len();
+            // A cursor over a block can have whole lines left over.
+            match self.peek() {
+                Some('\n') => Err(self.error("no more lines")),
+                _ => Err(self.error("end of line")),
+            }
+        }
+    }
+
+    /// Splits the text into its non-blank lines, keeping line numbers. Only
+    /// meaningful at the start of a line.
+    pub fn lines(&self) -> impl Iterator<Item = Cursor<'a>> {
+        let (first, _) = self.location();
+        self.rest()
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(move |(i, l)| Cursor::at_line(l, first + i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_say_where_and_what() {
+        let mut line = Cursor::new("p=0,4 v=3,-3");
+        line.expect("p=").unwrap();
+        line.number::<i32>().unwrap();
+        let e = line.expect(";").unwrap_err();
+        assert_eq!(
+            e,
+            ParseError {
+                line: 1,
+                column: 4,
+                expected: "\";\"".to_string(),
+                found: "','".to_string(),
+            }
+        );
+        assert_eq!(e.to_string(), "line 1, column 4: expected \";\", found ','");
+    }
+
+    #[test]
+    fn lines_and_blocks_keep_their_line_numbers() {
+        let input = "1 2\n\n3 x\n\n\n4\n5 6!\n";
+        let cursors: Vec<_> = lines(input).collect();
+        assert_eq!(cursors.len(), 4);
+        let mut third = cursors[1].clone();
+        third.number::<u8>().unwrap();
+        third.skip_spaces();
+        assert_eq!(
+            third.number::<u8>().unwrap_err().to_string(),
+            "line 3, column 3: expected a number, found 'x'"
+        );
+
+        let blocks = blocks(input);
+        assert_eq!(blocks.len(), 3);
+        let mut last = blocks[2].clone();
+        last.number::<u8>().unwrap();
+        assert_eq!(
+            last.end().unwrap_err().to_string(),
+            "line 6, column 2: expected no more lines, found end of line"
+        );
+    }
+
+    #[test]
+    fn numbers_must_fit() {
+        let mut c = Cursor::new("300");
+        assert_eq!(
+            c.number::<u8>().unwrap_err().to_string(),
+            "line 1, column 1: expected a number that fits, not 300, found '3'"
+        );
+        assert_eq!(Cursor::new("-12").number::<i8>().unwrap(), -12);
+    }
+
+    #[test]
+    fn sections_must_be_counted() {
+        assert_eq!(
+            sections::<2>("a\n\nb\n\nc\n").unwrap_err().to_string(),
+            "line 5, column 1: expected end of input, found 'c'"
+        );
+        assert_eq!(
+            sections::<2>("a\nb\n").unwrap_err().to_string(),
+            "line 3, column 1: expected 2 sections separated by blank lines, found end of input"
+        );
+    }
+}
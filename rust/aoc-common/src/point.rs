@@ -0,0 +1,115 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Direction;
+
+/// A position (or offset) on a grid, addressed as row then column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    /// Offsets of the four orthogonal neighbors, clockwise from up.
+    pub const NEIGHBORS4: [Point; 4] = [
+        Point::new(-1, 0),
+        Point::new(0, 1),
+        Point::new(1, 0),
+        Point::new(0, -1),
+    ];
+
+    /// Offsets of all eight surrounding cells, clockwise from up.
+    pub const NEIGHBORS8: [Point; 8] = [
+        Point::new(-1, 0),
+        Point::new(-1, 1),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(1, 0),
+        Point::new(1, -1),
+        Point::new(0, -1),
+        Point::new(-1, -1),
+    ];
+
+    pub const fn new(row: i64, col: i64) -> Self {
+        Point { row, col }
+    }
+
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Self::NEIGHBORS4.into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Self::NEIGHBORS8.into_iter().map(move |d| self + d)
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((row, col): (i64, i64)) -> Self {
+        Point::new(row, col)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row as i64, col as i64)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
@@ -0,0 +1,116 @@
+use std::any::Any;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+use crate::{Answer, Param, Params, Result};
+
+/// One day's puzzle: a parser, and the two parts that share its output.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input: 'static;
+
+    /// The day's tunable constants. Their defaults suit the real input.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Result<Answer>;
+
+    fn part2(&self, _input: &Self::Input, _params: &Params) -> Result<Answer> {
+        Err("part 2 is not implemented".into())
+    }
+}
+
+/// A [`Solution`] with its input type erased, so that every day can be
+/// handled the same way. Implemented for all solutions.
+pub trait Puzzle {
+    fn params(&self) -> &'static [Param];
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// Solves `part` (1 or 2) of an input returned by [`Puzzle::parse`].
+    fn solve(&self, input: &dyn Any, part: u8, params: &Params) -> Result<Answer>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8, params: &Params) -> Result<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or("input was parsed by a different puzzle")?;
+        match part {
+            1 => self.part1(input, params),
+            2 => self.part2(input, params),
+            _ => Err(format!("there is no part {}", part).into()),
+        }
+    }
+}
+
+/// Solves each input file named on the command line, or stdin if there are
+/// none, and prints both answers. A part that fails is reported on stderr
+/// without stopping the other. Passing `-v` or `--verbose` turns on the
+/// solutions' debug output, and `-p name=value` overrides a parameter.
+pub fn run(puzzle: &dyn Puzzle) -> Result<()> {
+    let mut params = Params::new(puzzle.params());
+    let mut files = vec![];
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "--verbose" => crate::set_verbose(true),
+            "-p" | "--param" => {
+                let assignment = args.next().ok_or("-p needs a name=value")?;
+                params.set_from_str(&assignment)?;
+            }
+            _ => files.push(arg),
+        }
+    }
+
+    if files.is_empty() {
+        return solve_all(puzzle, &read(None::<&str>)?, &params);
+    }
+
+    for file in &files {
+        if files.len() > 1 {
+            println!("{}:", file);
+        }
+        solve_all(puzzle, &read(Some(file))?, &params)?;
+    }
+
+    Ok(())
+}
+
+/// The contents of the named file, or of stdin if there is none. A file that
+/// cannot be read is named in the error.
+pub fn read(file: Option<impl AsRef<str>>) -> Result<String> {
+    match file {
+        Some(file) => {
+            let file = file.as_ref();
+            fs::read_to_string(file).map_err(|e| format!("{}: {}", file, e).into())
+        }
+        None => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            Ok(text)
+        }
+    }
+}
+
+fn solve_all(puzzle: &dyn Puzzle, text: &str, params: &Params) -> Result<()> {
+    let input = puzzle.parse(text)?;
+    for part in [1, 2] {
+        match puzzle.solve(input.as_ref(), part, params) {
+            Ok(answer) => println!("{}", answer),
+            Err(e) => eprintln!("part {}: {}", part, e),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Generates one test per answer listed in the days' `input/expected.toml`
+//! files; `tests/expected.rs` includes the result.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use aoc_common::Result;
+
+#[path = "src/expected.rs"]
+mod expected;
+
+fn main() -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let mut days: Vec<(u8, std::path::PathBuf)> = vec![];
+    for entry in fs::read_dir(&root)? {
+        let path = entry?.path();
+        let day = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_prefix("aoc"))
+            .and_then(|n| n.parse().ok());
+        if let Some(day) = day {
+            days.push((day, path.join("input")));
+        }
+    }
+    days.sort();
+
+    let mut tests = String::new();
+    for (day, dir) in days {
+        println!("cargo:rerun-if-changed={}", dir.display());
+
+        let path = dir.join("expected.toml");
+        let Ok(text) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let inputs = expected::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        for (name, expected) in inputs {
+            for part in [1, 2] {
+                if expected.part(part).is_none() {
+                    continue;
+                }
+
+                let ident: String = name
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .collect();
+                writeln!(tests, "#[test]")?;
+                if expected.slow {
+                    writeln!(tests, "#[ignore = \"slow\"]")?;
+                }
+                writeln!(
+                    tests,
+                    "fn day{:02}_{}_part{}() {{ check({}, {:?}, {}) }}\n",
+                    day, ident, part, day, name, part
+                )?;
+            }
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR")?).join("expected_tests.rs");
+    fs::write(out, tests)?;
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+//! `aoc bench`: times parsing and each part separately for every day, so a
+//! run can be saved as JSON and compared against a later one.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_common::Result;
+use serde::{Deserialize, Serialize};
+
+/// The timings of one benchmark run over a set of days.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub input: String,
+    pub runs: usize,
+    pub days: Vec<DayTimings>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub parse: Phase,
+    pub part1: Phase,
+    pub part2: Phase,
+}
+
+/// How long one phase took over all runs, or why it could not be timed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Phase {
+    Timed(Stats),
+    Failed { error: String },
+}
+
+/// Summary statistics, in nanoseconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Phase {
+    pub fn median(&self) -> Option<Duration> {
+        match self {
+            Phase::Timed(stats) => Some(Duration::from_nanos(stats.median_ns)),
+            Phase::Failed { .. } => None,
+        }
+    }
+}
+
+impl DayTimings {
+    /// The median time to parse and solve both parts, if all three succeeded.
+    pub fn total(&self) -> Option<Duration> {
+        Some(self.parse.median()? + self.part1.median()? + self.part2.median()?)
+    }
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn day(&self, day: u8) -> Option<&DayTimings> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+impl DayTimings {
+    /// The timings of a day whose input never got as far as the parts.
+    fn failed(day: u8, error: String, why: &str) -> DayTimings {
+        let failed = |error: String| Phase::Failed { error };
+        DayTimings {
+            day,
+            parse: failed(error),
+            part1: failed(why.to_string()),
+            part2: failed(why.to_string()),
+        }
+    }
+}
+
+/// Benchmarks `day` on an input resolved by [`crate::resolve_input`],
+/// parsing and solving it `runs` times. Returns `None` if the input file is
+/// empty. An input that cannot be read fails its parse phase.
+pub fn day(day: u8, input: &str, runs: usize) -> Result<Option<DayTimings>> {
+    let puzzle = crate::solution(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    let path = crate::resolve_input(day, input);
+    let unread = "the input could not be read";
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => {
+            let error = format!("{}: {}", path.display(), e);
+            return Ok(Some(DayTimings::failed(day, error, unread)));
+        }
+    };
+    if text.trim().is_empty() {
+        return Ok(None);
+    }
+    let params = match crate::params(day, &path) {
+        Ok(params) => params,
+        Err(e) => return Ok(Some(DayTimings::failed(day, e.to_string(), unread))),
+    };
+
+    let mut parse = vec![];
+    let mut parts: [std::result::Result<Vec<Duration>, String>; 2] = [Ok(vec![]), Ok(vec![])];
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let parsed = match puzzle.parse(&text) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let why = "the input did not parse";
+                return Ok(Some(DayTimings::failed(day, e.to_string(), why)));
+            }
+        };
+        parse.push(start.elapsed());
+
+        for (part, times) in (1..).zip(&mut parts) {
+            let Ok(list) = times else {
+                continue;
+            };
+            let start = Instant::now();
+            match puzzle.solve(parsed.as_ref(), part, &params) {
+                Ok(_) => list.push(start.elapsed()),
+                Err(e) => *times = Err(e.to_string()),
+            }
+        }
+    }
+
+    let [part1, part2] = parts.map(|times| match times {
+        Ok(mut times) => Phase::Timed(stats(&mut times)),
+        Err(error) => Phase::Failed { error },
+    });
+
+    Ok(Some(DayTimings {
+        day,
+        parse: Phase::Timed(stats(&mut parse)),
+        part1,
+        part2,
+    }))
+}
+
+fn stats(times: &mut [Duration]) -> Stats {
+    times.sort();
+    let nanos = |d: Duration| d.as_nanos() as u64;
+    let total: Duration = times.iter().sum();
+    Stats {
+        min_ns: nanos(times[0]),
+        median_ns: nanos(times[times.len() / 2]),
+        mean_ns: nanos(total / times.len() as u32),
+    }
+}
@@ -0,0 +1,65 @@
+//! Known answers, read from `aocNN/input/expected.toml`. Each table is named
+//! after an input file and lists the answer to each part, e.g.
+//!
+//! ```toml
+//! [sample]
+//! part1 = 143
+//! part2 = "co,de,ka,ta"
+//! slow = true
+//! ```
+//!
+//! `slow` marks inputs that take too long to check on every `cargo test`.
+
+use std::collections::BTreeMap;
+
+use aoc_common::Result;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub slow: bool,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an `expected.toml`, keyed by input name.
+pub fn parse(text: &str) -> Result<BTreeMap<String, Expected>> {
+    let table: toml::Table = text.parse()?;
+
+    let mut inputs = BTreeMap::new();
+    for (name, value) in table {
+        let toml::Value::Table(fields) = value else {
+            return Err(format!("`{}` should be a table", name).into());
+        };
+
+        let mut expected = Expected::default();
+        for (key, value) in fields {
+            match (key.as_str(), value) {
+                ("part1", v) => expected.part1 = Some(answer(&name, &key, v)?),
+                ("part2", v) => expected.part2 = Some(answer(&name, &key, v)?),
+                ("slow", toml::Value::Boolean(b)) => expected.slow = b,
+                _ => return Err(format!("unexpected key `{}.{}`", name, key).into()),
+            }
+        }
+        inputs.insert(name, expected);
+    }
+
+    Ok(inputs)
+}
+
+fn answer(name: &str, key: &str, value: toml::Value) -> Result<String> {
+    match value {
+        toml::Value::Integer(n) => Ok(n.to_string()),
+        toml::Value::String(s) => Ok(s),
+        _ => Err(format!("`{}.{}` should be an integer or a string", name, key).into()),
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Params, Puzzle, Result};
+
+pub mod bench;
+pub mod expected;
+pub mod scaffold;
+
+pub use expected::Expected;
+
+const DAYS: &[(u8, &dyn Puzzle)] = &[
+    (1, &aoc01::Day01),
+    (2, &aoc02::Day02),
+    (3, &aoc03::Day03),
+    (4, &aoc04::Day04),
+    (5, &aoc05::Day05),
+    (6, &aoc06::Day06),
+    (7, &aoc07::Day07),
+    (8, &aoc08::Day08),
+    (9, &aoc09::Day09),
+    (10, &aoc10::Day10),
+    (11, &aoc11::Day11),
+    (12, &aoc12::Day12),
+    (13, &aoc13::Day13),
+    (14, &aoc14::Day14),
+    (15, &aoc15::Day15),
+    (16, &aoc16::Day16),
+    (17, &aoc17::Day17),
+    (18, &aoc18::Day18),
+    (19, &aoc19::Day19),
+    (20, &aoc20::Day20),
+    (21, &aoc21::Day21),
+    (22, &aoc22::Day22),
+    (23, &aoc23::Day23),
+    (24, &aoc24::Day24),
+    (25, &aoc25::Day25),
+];
+
+/// The solution for `day` (1-based), if there is one.
+pub fn solution(day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().find(|(d, _)| *d == day).map(|(_, s)| *s)
+}
+
+pub fn days() -> impl Iterator<Item = u8> {
+    DAYS.iter().map(|(d, _)| *d)
+}
+
+/// The path of a named input for `day`, e.g. `sample` is `aoc07/input/sample.txt`.
+pub fn input_path(day: u8, name: &str) -> PathBuf {
+    input_dir(day).join(format!("{}.txt", name))
+}
+
+/// The known answers for `day`'s inputs. A day without an `expected.toml` has none.
+pub fn expected(day: u8) -> Result<BTreeMap<String, Expected>> {
+    let path = input_dir(day).join("expected.toml");
+    match fs::read_to_string(&path) {
+        Ok(text) => expected::parse(&text).map_err(|e| format!("{}: {}", path.display(), e).into()),
+        Err(_) if !path.exists() => Ok(BTreeMap::new()),
+        Err(e) => Err(format!("{}: {}", path.display(), e).into()),
+    }
+}
+
+/// The parameters for solving `day` on the input file at `input`: the
+/// defaults, overridden by the input's table in a `params.toml` next to it.
+/// For `sample.txt` that might be
+///
+/// ```toml
+/// [sample]
+/// width = 11
+/// height = 7
+/// ```
+pub fn params(day: u8, input: &Path) -> Result<Params> {
+    let solution = solution(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    let mut params = Params::new(solution.params());
+
+    let path = input.with_file_name("params.toml");
+    let Ok(text) = fs::read_to_string(&path) else {
+        return Ok(params);
+    };
+    let context = |e: String| format!("{}: {}", path.display(), e);
+    let table: toml::Table = text.parse().map_err(|e| context(format!("{}", e)))?;
+
+    let name = input.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    let Some(overrides) = table.get(name) else {
+        return Ok(params);
+    };
+    let overrides = overrides
+        .as_table()
+        .ok_or_else(|| context(format!("`{}` should be a table", name)))?;
+    for (key, value) in overrides {
+        let value = value
+            .as_integer()
+            .ok_or_else(|| context(format!("`{}.{}` should be an integer", name, key)))?;
+        params
+            .set(key, value)
+            .map_err(|e| context(format!("[{}] {}", name, e)))?;
+    }
+
+    Ok(params)
+}
+
+/// Solves one part of `day` for the named input and compares it with the
+/// answer recorded in `expected.toml`.
+pub fn check(day: u8, name: &str, part: u8) -> Result<()> {
+    let solution = solution(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    let want = expected(day)?
+        .get(name)
+        .and_then(|e| e.part(part).map(str::to_string))
+        .ok_or_else(|| {
+            format!(
+                "day {} has no expected answer for {} part {}",
+                day, name, part
+            )
+        })?;
+
+    let path = input_path(day, name);
+    let params = params(day, &path)?;
+    let input = fs::read_to_string(&path)?;
+    let got = solution.solve(solution.parse(&input)?.as_ref(), part, &params)?;
+
+    if got.to_string() != want {
+        return Err(format!(
+            "day {} {} part {}: got {}, expected {}",
+            day, name, part, got, want
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Resolves an `--input` argument for `day`. An existing file is used as
+/// given; anything else names a file in the day's `input/` directory, with or
+/// without the `.txt`.
+pub fn resolve_input(day: u8, input: &str) -> PathBuf {
+    let path = PathBuf::from(input);
+    if path.is_file() {
+        path
+    } else {
+        input_path(day, input.strip_suffix(".txt").unwrap_or(input))
+    }
+}
+
+fn input_dir(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("aoc{:02}", day))
+        .join("input")
+}
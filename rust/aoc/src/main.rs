@@ -0,0 +1,345 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use aoc::bench::{self, Phase, Report};
+use aoc_common::{Answer, Params, Result};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    /// Print the solutions' debug output to stderr
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day
+    Run {
+        day: u8,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input names, resolved to `aocNN/input/<name>.txt`, or paths to
+        /// input files. Each one is solved in turn.
+        #[arg(long, default_value = "input", num_args = 1..)]
+        input: Vec<String>,
+        /// Override a parameter, e.g. `--param width=11`. Takes precedence
+        /// over the input's `params.toml`.
+        #[arg(short, long = "param", value_name = "NAME=VALUE")]
+        params: Vec<String>,
+    },
+    /// Run every day in sequence and print a summary table
+    All {
+        /// Input name, resolved to `aocNN/input/<name>.txt`
+        #[arg(long, default_value = "input")]
+        input: String,
+    },
+    /// Time parsing and each part of every day (or just the given days)
+    Bench {
+        days: Vec<u8>,
+        /// Input name, resolved to `aocNN/input/<name>.txt`
+        #[arg(long, default_value = "input")]
+        input: String,
+        /// How many times to parse and solve each day
+        #[arg(long, default_value_t = 3)]
+        runs: usize,
+        /// Save the timings as JSON to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against timings saved by an earlier `--save`
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// List a day's parameters and their defaults
+    Params { day: u8 },
+    /// Create and register the crate for a new day
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    aoc_common::set_verbose(cli.verbose);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            params,
+        } => run(day, part, &input, &params),
+        Command::All { input } => all(&input),
+        Command::Bench {
+            days,
+            input,
+            runs,
+            save,
+            baseline,
+        } => bench(&days, &input, runs, save.as_deref(), baseline.as_deref()),
+        Command::Params { day } => params(day),
+        Command::New { day } => {
+            let dir = aoc::scaffold::new_day(day)?;
+            println!("Created {}", dir.display());
+            Ok(())
+        }
+    }
+}
+
+fn run(day: u8, part: Option<u8>, inputs: &[String], overrides: &[String]) -> Result<()> {
+    let solution = aoc::solution(day).ok_or_else(|| format!("no solution for day {}", day))?;
+
+    let mut failed = 0;
+    for name in inputs {
+        if inputs.len() > 1 {
+            println!("{}:", name);
+        }
+
+        let input = read_input(day, name).and_then(|(text, mut params)| {
+            for o in overrides {
+                params
+                    .set_from_str(o)
+                    .map_err(|e| format!("{}.txt: {}", name, e))?;
+            }
+            Ok((solution.parse(&text)?, params))
+        });
+        let (input, params) = match input {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {}: error: {}", day, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for p in [1, 2] {
+            if part.is_some_and(|part| part != p) {
+                continue;
+            }
+
+            let (answer, elapsed) = timed(|| solution.solve(input.as_ref(), p, &params));
+            match answer {
+                Ok(answer) => println!("Day {} part {}: {} ({:.1?})", day, p, answer, elapsed),
+                Err(e) => println!("Day {} part {}: error: {}", day, p, e),
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!(
+            "{} of {} inputs could not be read or parsed",
+            failed,
+            inputs.len()
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn all(input: &str) -> Result<()> {
+    let mut rows = vec![];
+    for day in aoc::days() {
+        let Some(solution) = aoc::solution(day) else {
+            continue;
+        };
+
+        let (text, params) = match read_input(day, input) {
+            Ok((text, params)) if !text.trim().is_empty() => (text, params),
+            Ok(_) => {
+                rows.push(row(day, "no input", "no input", ""));
+                continue;
+            }
+            Err(e) => {
+                rows.push(row(day, &e.to_string(), "", ""));
+                continue;
+            }
+        };
+
+        let (input, t0) = timed(|| solution.parse(&text));
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                let e = format!("error: {}", e);
+                rows.push(row(day, &e, &e, &format!("{:.1?}", t0)));
+                continue;
+            }
+        };
+
+        let (part1, t1) = timed(|| solution.solve(input.as_ref(), 1, &params));
+        let (part2, t2) = timed(|| solution.solve(input.as_ref(), 2, &params));
+        rows.push(row(
+            day,
+            &cell(part1),
+            &cell(part2),
+            &format!("{:.1?}", t0 + t1 + t2),
+        ));
+    }
+
+    print_table(&["Day", "Part 1", "Part 2", "Time"], &rows);
+
+    Ok(())
+}
+
+fn params(day: u8) -> Result<()> {
+    let solution = aoc::solution(day).ok_or_else(|| format!("no solution for day {}", day))?;
+    if solution.params().is_empty() {
+        println!("Day {} has no parameters", day);
+        return Ok(());
+    }
+
+    let rows: Vec<Vec<String>> = solution
+        .params()
+        .iter()
+        .map(|p| {
+            vec![
+                p.name.to_string(),
+                p.default.to_string(),
+                p.range(),
+                p.help.to_string(),
+            ]
+        })
+        .collect();
+    print_table(&["Name", "Default", "Range", "Description"], &rows);
+
+    Ok(())
+}
+
+fn bench(
+    days: &[u8],
+    input: &str,
+    runs: usize,
+    save: Option<&Path>,
+    baseline: Option<&Path>,
+) -> Result<()> {
+    let baseline = baseline.map(Report::load).transpose()?;
+    let days: Vec<u8> = if days.is_empty() {
+        aoc::days().collect()
+    } else {
+        days.to_vec()
+    };
+
+    let mut report = Report {
+        input: input.to_string(),
+        runs,
+        days: vec![],
+    };
+    let mut rows = vec![];
+    let mut failed = 0;
+    for day in days {
+        let Some(timings) = bench::day(day, input, runs)? else {
+            continue;
+        };
+        if let Phase::Failed { .. } = timings.parse {
+            failed += 1;
+        }
+
+        let total = timings.total();
+        let mut row = vec![
+            day.to_string(),
+            phase(&timings.parse),
+            phase(&timings.part1),
+            phase(&timings.part2),
+            total.map_or(String::new(), |t| format!("{:.1?}", t)),
+        ];
+        if let Some(baseline) = &baseline {
+            let before = baseline.day(day).and_then(|d| d.total());
+            row.push(match (before, total) {
+                (Some(before), Some(after)) => format!(
+                    "{:+.1}%",
+                    100.0 * (after.as_secs_f64() / before.as_secs_f64() - 1.0)
+                ),
+                _ => String::new(),
+            });
+        }
+        rows.push(row);
+        report.days.push(timings);
+    }
+
+    let mut header = vec!["Day", "Parse", "Part 1", "Part 2", "Total"];
+    if baseline.is_some() {
+        header.push("Change");
+    }
+    print_table(&header, &rows);
+
+    if let Some(path) = save {
+        report.save(path)?;
+    }
+
+    if failed > 0 {
+        return Err(format!(
+            "{} of {} inputs could not be read or parsed",
+            failed,
+            report.days.len()
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// The median time of a phase, or its error.
+fn phase(phase: &Phase) -> String {
+    match phase {
+        Phase::Timed(stats) => format!("{:.1?}", Duration::from_nanos(stats.median_ns)),
+        Phase::Failed { error } => format!("error: {}", error),
+    }
+}
+
+/// The text of an input for `day`, and the parameters to solve it with.
+fn read_input(day: u8, input: &str) -> Result<(String, Params)> {
+    let path = aoc::resolve_input(day, input);
+    let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok((text, aoc::params(day, &path)?))
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+fn cell(answer: Result<Answer>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+fn row(day: u8, part1: &str, part2: &str, time: &str) -> Vec<String> {
+    vec![
+        day.to_string(),
+        part1.to_string(),
+        part2.to_string(),
+        time.to_string(),
+    ]
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+
+    let line = |cells: &mut dyn Iterator<Item = &str>| {
+        let padded: Vec<String> = cells
+            .zip(&widths)
+            .map(|(c, w)| format!("{:<w$}", c, w = w))
+            .collect();
+        println!("{}", padded.join(" | ").trim_end());
+    };
+
+    let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    line(&mut header.iter().copied());
+    line(&mut rule.iter().map(String::as_str));
+    for row in rows {
+        line(&mut row.iter().map(String::as_str));
+    }
+}
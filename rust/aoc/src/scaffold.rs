@@ -0,0 +1,122 @@
+//! `aoc new <day>`: creates a day crate from the files in `template/` and
+//! registers it with the workspace and the runner.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::Result;
+use toml_edit::{value, DocumentMut, InlineTable};
+
+const CARGO_TOML: &str = include_str!("../template/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../template/lib.rs.in");
+const MAIN_RS: &str = include_str!("../template/main.rs.in");
+const EXPECTED_TOML: &str = include_str!("../template/expected.toml.in");
+
+/// Scaffolds `aocNN` in the workspace containing the current directory and
+/// returns the new crate's path.
+pub fn new_day(day: u8) -> Result<PathBuf> {
+    let root = workspace_root()?;
+    let name = format!("aoc{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    let fill = |template: &str| template.replace("{{NN}}", &format!("{:02}", day));
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("input"))?;
+    fs::write(dir.join("Cargo.toml"), fill(CARGO_TOML))?;
+    fs::write(dir.join("src/lib.rs"), fill(LIB_RS))?;
+    fs::write(dir.join("src/main.rs"), fill(MAIN_RS))?;
+    fs::write(dir.join("input/sample.txt"), "")?;
+    fs::write(dir.join("input/input.txt"), "")?;
+    fs::write(dir.join("input/expected.toml"), fill(EXPECTED_TOML))?;
+
+    add_member(&root.join("Cargo.toml"), &name)?;
+    add_dependency(&root.join("aoc/Cargo.toml"), &name)?;
+    add_day(&root.join("aoc/src/lib.rs"), day)?;
+
+    Ok(dir)
+}
+
+/// The closest directory at or above the current one whose `Cargo.toml`
+/// declares a `[workspace]`.
+fn workspace_root() -> Result<PathBuf> {
+    let cwd = env::current_dir()?;
+    for dir in cwd.ancestors() {
+        let Ok(text) = fs::read_to_string(dir.join("Cargo.toml")) else {
+            continue;
+        };
+        if text.parse::<DocumentMut>()?.contains_key("workspace") {
+            return Ok(dir.to_path_buf());
+        }
+    }
+
+    Err(format!("{} is not inside a cargo workspace", cwd.display()).into())
+}
+
+fn add_member(manifest: &Path, name: &str) -> Result<()> {
+    let mut doc: DocumentMut = fs::read_to_string(manifest)?.parse()?;
+    let members = doc["workspace"]["members"]
+        .as_array_mut()
+        .ok_or("workspace.members should be an array")?;
+
+    let at = members
+        .iter()
+        .position(|m| m.as_str().is_some_and(|m| m > name))
+        .unwrap_or(members.len());
+    members.insert(at, name);
+
+    fs::write(manifest, doc.to_string())?;
+    Ok(())
+}
+
+fn add_dependency(manifest: &Path, name: &str) -> Result<()> {
+    let mut doc: DocumentMut = fs::read_to_string(manifest)?.parse()?;
+    let deps = doc["dependencies"]
+        .as_table_mut()
+        .ok_or("dependencies should be a table")?;
+
+    let mut dep = InlineTable::new();
+    dep.insert("path", format!("../{}", name).into());
+    deps.insert(name, value(dep));
+    deps.sort_values();
+
+    fs::write(manifest, doc.to_string())?;
+    Ok(())
+}
+
+/// Adds the day to the runner's `DAYS` table, keeping it in day order.
+fn add_day(lib: &Path, day: u8) -> Result<()> {
+    let text = fs::read_to_string(lib)?;
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("const DAYS"))
+        .ok_or("could not find DAYS in the runner")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| *l == "];")
+            .ok_or("could not find the end of DAYS")?;
+
+    let entry = format!("    ({}, &aoc{:02}::Day{:02}),", day, day, day);
+    let at = (start + 1..end)
+        .find(|&i| entry_day(lines[i]).is_some_and(|d| d > day))
+        .unwrap_or(end);
+    lines.insert(at, &entry);
+
+    fs::write(lib, lines.join("\n") + "\n")?;
+    Ok(())
+}
+
+fn entry_day(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix('(')?
+        .split(',')
+        .next()?
+        .parse()
+        .ok()
+}
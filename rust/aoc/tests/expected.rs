@@ -0,0 +1,10 @@
+//! Checks every day against the answers in its `input/expected.toml`. Slow
+//! inputs are ignored by default; run them with `cargo test -- --ignored`.
+
+fn check(day: u8, name: &str, part: u8) {
+    if let Err(e) = aoc::check(day, name, part) {
+        panic!("{}", e);
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/expected_tests.rs"));
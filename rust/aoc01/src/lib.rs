@@ -0,0 +1,72 @@
+use aoc_common::{parse, Answer, Params, Result, Solution};
+use std::collections::HashMap;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Lists;
+
+    fn parse(&self, input: &str) -> Result<Lists> {
+        parse(input)
+    }
+
+    fn part1(&self, lists: &Lists, _params: &Params) -> Result<Answer> {
+        part1(lists)
+    }
+
+    fn part2(&self, lists: &Lists, _params: &Params) -> Result<Answer> {
+        part2(lists)
+    }
+}
+
+/// The two location lists, read side by side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lists {
+    pub left: Vec<i32>,
+    pub right: Vec<i32>,
+}
+
+pub fn parse(input: &str) -> Result<Lists> {
+    let mut lists = Lists {
+        left: vec![],
+        right: vec![],
+    };
+    for mut line in parse::lines(input) {
+        lists.left.push(line.number()?);
+        line.skip_spaces();
+        lists.right.push(line.number()?);
+        line.end()?;
+    }
+    Ok(lists)
+}
+
+fn part1(lists: &Lists) -> Result<Answer> {
+    let mut left_list = lists.left.clone();
+    let mut right_list = lists.right.clone();
+    left_list.sort();
+    right_list.sort();
+
+    let mut sum = 0;
+    for i in 0..left_list.len() {
+        sum += (left_list[i] - right_list[i]).abs();
+    }
+
+    Ok(sum.into())
+}
+
+fn part2(lists: &Lists) -> Result<Answer> {
+    let mut counts = HashMap::new();
+
+    for &i in &lists.right {
+        counts.entry(i).and_modify(|e| *e += 1).or_insert(1);
+    }
+
+    let mut sum = 0;
+    for &i in &lists.left {
+        if let Some(v) = counts.get(&i) {
+            sum += *v * i;
+        }
+    }
+
+    Ok(sum.into())
+}
@@ -0,0 +1,87 @@
+use aoc_common::{parse, Answer, Cursor, Params, Result, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Report>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Report>> {
+        parse(input)
+    }
+
+    fn part1(&self, reports: &Vec<Report>, _params: &Params) -> Result<Answer> {
+        part1(reports)
+    }
+
+    fn part2(&self, reports: &Vec<Report>, _params: &Params) -> Result<Answer> {
+        part2(reports)
+    }
+}
+
+/// The levels of one report, in order.
+pub type Report = Vec<i32>;
+
+pub fn parse(input: &str) -> Result<Vec<Report>> {
+    parse::lines(input).map(parse_report).collect()
+}
+
+fn part1(data: &[Report]) -> Result<Answer> {
+    let mut count = 0;
+    for row in data {
+        let is_safe = is_safe(row);
+        if is_safe {
+            count += 1;
+        }
+    }
+
+    Ok(count.into())
+}
+
+fn part2(data: &[Report]) -> Result<Answer> {
+    let mut count = 0;
+    for row in data {
+        let safe = is_safe(row);
+        if safe {
+            count += 1;
+        } else {
+            for i in 0..row.len() {
+                let mut row_copy = row.clone();
+                row_copy.remove(i);
+                let is_safe = is_safe(&row_copy);
+                if is_safe {
+                    count += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(count.into())
+}
+
+fn is_safe(row: &[i32]) -> bool {
+    let is_increasing = row.windows(2).all(|pair| pair[0] < pair[1]);
+    let is_decreasing = row.windows(2).all(|pair| pair[0] > pair[1]);
+    let diffs: Vec<i32> = row
+        .windows(2)
+        .map(|pair| (pair[0] - pair[1]).abs())
+        .collect();
+    let in_range = diffs.iter().all(|diff| *diff >= 1 && *diff <= 3);
+    let mut is_safe = false;
+    if (is_increasing || is_decreasing) && (in_range) {
+        is_safe = true;
+    }
+
+    // println!(
+    //     "{:?} increasing: {}, decreasing: {}, diffs: {:?}, in_range: {} -> {is_safe}",
+    //     row, is_increasing, is_decreasing, diffs, in_range
+    // );
+
+    is_safe
+}
+
+fn parse_report(mut line: Cursor) -> Result<Report> {
+    let levels = line.separated(" ", |l| l.number())?;
+    line.end()?;
+    Ok(levels)
+}
@@ -0,0 +1,85 @@
+use aoc_common::{Answer, Cursor, Params, Result, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, program: &Vec<Instruction>, _params: &Params) -> Result<Answer> {
+        part1(program)
+    }
+
+    fn part2(&self, program: &Vec<Instruction>, _params: &Params) -> Result<Answer> {
+        part2(program)
+    }
+}
+
+/// An instruction recovered from the corrupted memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+/// Scans the memory for well-formed instructions, skipping everything else.
+pub fn parse(input: &str) -> Vec<Instruction> {
+    let mut memory = Cursor::new(input);
+    let mut program = vec![];
+    while !memory.is_empty() {
+        if memory.eat("do()") {
+            program.push(Instruction::Do);
+        } else if memory.eat("don't()") {
+            program.push(Instruction::Dont);
+        } else if let Some((a, b)) = mul(&mut memory) {
+            program.push(Instruction::Mul(a, b));
+        } else {
+            memory.bump();
+        }
+    }
+    program
+}
+
+fn part1(program: &[Instruction]) -> Result<Answer> {
+    let mut sum = 0;
+    for instruction in program {
+        if let Instruction::Mul(a, b) = instruction {
+            sum += a * b;
+        }
+    }
+
+    Ok(sum.into())
+}
+
+fn part2(program: &[Instruction]) -> Result<Answer> {
+    let mut enabled = true;
+    let mut sum = 0;
+    for instruction in program {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+            Instruction::Mul(a, b) if enabled => sum += a * b,
+            Instruction::Mul(..) => {}
+        }
+    }
+
+    Ok(sum.into())
+}
+
+/// Reads a well-formed `mul(a,b)` at the cursor. Anything else is corrupted
+/// memory and leaves the cursor where it was.
+fn mul(memory: &mut Cursor) -> Option<(i32, i32)> {
+    let mut c = memory.clone();
+    c.expect("mul(").ok()?;
+    let a: i32 = c.take_while(|ch| ch.is_ascii_digit()).parse().ok()?;
+    c.expect(",").ok()?;
+    let b: i32 = c.take_while(|ch| ch.is_ascii_digit()).parse().ok()?;
+    c.expect(")").ok()?;
+
+    *memory = c;
+    Some((a, b))
+}
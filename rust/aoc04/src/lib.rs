@@ -0,0 +1,73 @@
+use aoc_common::{Answer, Grid, Params, Point, Result, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>, _params: &Params) -> Result<Answer> {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<char>, _params: &Params) -> Result<Answer> {
+        part2(grid)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input)
+}
+
+fn part1(grid: &Grid<char>) -> Result<Answer> {
+    // println!("{:?}", grid);
+
+    let xmas = ['X', 'M', 'A', 'S'];
+    let mut count = 0;
+    for start in grid.points() {
+        for dir in Point::NEIGHBORS8 {
+            let found = xmas
+                .iter()
+                .enumerate()
+                .all(|(k, c)| grid.get(start + dir * k as i64) == Some(c));
+
+            if found {
+                count += 1;
+            }
+        }
+    }
+
+    Ok(count.into())
+}
+
+fn part2(grid: &Grid<char>) -> Result<Answer> {
+    // println!("{:?}", grid);
+
+    let mut count = 0;
+    for p in grid.positions(|c| *c == 'A') {
+        // check the for corners for 'S' or 'M'
+        // Extract the characters at the diagonal positions
+        let corner = |dr, dc| grid.get(p + Point::new(dr, dc)).copied();
+        let (Some(ul), Some(ur), Some(ll), Some(lr)) =
+            (corner(-1, -1), corner(-1, 1), corner(1, -1), corner(1, 1))
+        else {
+            continue;
+        };
+
+        // Check first diagonal (upper-left to lower-right)
+        let arm1_valid = (ul == 'M' && lr == 'S') || (ul == 'S' && lr == 'M');
+
+        // Check second diagonal (upper-right to lower-left)
+        let arm2_valid = (ur == 'M' && ll == 'S') || (ur == 'S' && ll == 'M');
+
+        // If both arms form MAS or SAM sequences, increment count
+        if arm1_valid && arm2_valid {
+            count += 1;
+        }
+    }
+
+    Ok(count.into())
+}
@@ -0,0 +1,103 @@
+use aoc_common::{Answer, Direction, Grid, Params, Point, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>, _params: &Params) -> Result<Answer> {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<char>, _params: &Params) -> Result<Answer> {
+        part2(grid)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input)
+}
+
+fn part1(grid: &Grid<char>) -> Result<Answer> {
+    let start = grid.find(&'^').ok_or("no guard in map")?;
+
+    // println!("Start: {:?}", start);
+
+    let locs = simulate(start, grid);
+
+    Ok(locs.len().into())
+}
+
+fn part2(grid: &Grid<char>) -> Result<Answer> {
+    let mut grid = grid.clone();
+    let start = grid.find(&'^').ok_or("no guard in map")?;
+
+    let mut locs = HashSet::new();
+    for p in grid.points() {
+        if grid[p] == '.' {
+            grid[p] = '#';
+            if simulate_loop(start, &grid) {
+                // println!("Loop found at: {}", p);
+                locs.insert(p);
+            }
+            grid[p] = '.';
+        }
+    }
+
+    Ok(locs.len().into())
+}
+
+fn simulate(start: Point, grid: &Grid<char>) -> HashSet<Point> {
+    let mut dir = Direction::Up;
+
+    let mut locs: HashSet<Point> = HashSet::new();
+    locs.insert(start);
+
+    let mut current = start;
+    loop {
+        let next = current.step(dir);
+
+        // check if out of grid bounds
+        match grid.get(next) {
+            None => break,
+            // turn right
+            Some('#') => dir = dir.rotate_right(),
+            Some(_) => current = next,
+        }
+
+        locs.insert(current);
+    }
+
+    locs
+}
+
+fn simulate_loop(start: Point, grid: &Grid<char>) -> bool {
+    let mut dir = Direction::Up;
+
+    let mut visited: HashSet<(Point, Direction)> = HashSet::new();
+    visited.insert((start, dir));
+
+    let mut current = start;
+    loop {
+        let next = current.step(dir);
+
+        // check if out of grid bounds
+        match grid.get(next) {
+            None => break,
+            // turn right
+            Some('#') => dir = dir.rotate_right(),
+            Some(_) => current = next,
+        }
+
+        if !visited.insert((current, dir)) {
+            return true;
+        }
+    }
+
+    false
+}
@@ -0,0 +1,137 @@
+use aoc_common::{parse, Answer, Cursor, Params, Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Equation>> {
+        parse(input)
+    }
+
+    fn part1(&self, equations: &Vec<Equation>, _params: &Params) -> Result<Answer> {
+        part1(equations)
+    }
+
+    fn part2(&self, equations: &Vec<Equation>, _params: &Params) -> Result<Answer> {
+        part2(equations)
+    }
+}
+
+/// A calibration equation: a test value and the operands that should produce it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    pub test_value: i64,
+    pub vals: Vec<i64>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Equation>> {
+    parse::lines(input).map(parse_equation).collect()
+}
+
+fn parse_equation(mut line: Cursor) -> Result<Equation> {
+    let test_value = line.number()?;
+    line.expect(": ")?;
+    let vals = line.separated(" ", |l| l.number())?;
+    line.end()?;
+    Ok(Equation { test_value, vals })
+}
+
+fn part1(equations: &[Equation]) -> Result<Answer> {
+    let mut sum = 0;
+    for eq in equations {
+        let can_achieve = can_achieve_target(eq.test_value, &eq.vals);
+        if can_achieve {
+            sum += eq.test_value;
+        }
+    }
+
+    Ok(sum.into())
+}
+
+fn part2(equations: &[Equation]) -> Result<Answer> {
+    let mut sum = 0;
+    for eq in equations {
+        let can_achieve = can_achieve_target_dfs(eq.test_value, &eq.vals);
+        if can_achieve {
+            sum += eq.test_value;
+        }
+    }
+
+    Ok(sum.into())
+}
+
+fn can_achieve_target(target: i64, vals: &[i64]) -> bool {
+    let mut dp: Vec<HashSet<i64>> = vec![HashSet::new(); vals.len()];
+
+    dp[0].insert(vals[0]);
+
+    for i in 1..vals.len() {
+        let current = vals[i];
+        for j in dp[i - 1].iter().cloned().collect::<Vec<i64>>() {
+            dp[i].insert(j + current);
+            dp[i].insert(j * current);
+        }
+    }
+
+    for set in dp.iter() {
+        if set.contains(&target) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn can_achieve_target_dfs(target: i64, vals: &[i64]) -> bool {
+    let mut memo: HashMap<(i64, i64), bool> = HashMap::new();
+
+    dfs(1, vals[0], vals, target, &mut memo)
+}
+
+fn dfs(
+    current_index: i64,
+    current_value: i64,
+    vals: &[i64],
+    target: i64,
+    memo: &mut HashMap<(i64, i64), bool>,
+) -> bool {
+    if current_index as usize == vals.len() {
+        return current_value == target;
+    }
+
+    let state_key = (current_index, current_value);
+    if let Some(&result) = memo.get(&state_key) {
+        return result;
+    }
+
+    let next_num = vals[current_index as usize];
+
+    let mut can_form_target = false;
+
+    let new_current = current_value + next_num;
+    if dfs(current_index + 1, new_current, vals, target, memo) {
+        can_form_target = true;
+    }
+
+    let mul = current_value * next_num;
+    if dfs(current_index + 1, mul, vals, target, memo) {
+        can_form_target = true;
+    }
+
+    let concatenated_operand = concatenate_operands(current_value, next_num);
+    if dfs(current_index + 1, concatenated_operand, vals, target, memo) {
+        can_form_target = true;
+    }
+
+    memo.insert(state_key, can_form_target);
+    can_form_target
+}
+
+fn concatenate_operands(a: i64, b: i64) -> i64 {
+    let a_str = a.to_string();
+    let b_str = b.to_string();
+    let concat = format!("{}{}", a_str, b_str);
+    concat.parse().unwrap()
+}
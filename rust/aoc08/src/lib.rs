@@ -0,0 +1,98 @@
+use aoc_common::{Answer, Grid, Params, Point, Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>, _params: &Params) -> Result<Answer> {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<char>, _params: &Params) -> Result<Answer> {
+        part2(grid)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input)
+}
+
+fn part1(grid: &Grid<char>) -> Result<Answer> {
+    let freq = antennas(grid);
+
+    let mut antinodes = HashSet::new();
+    for anntenas in freq.values() {
+        if anntenas.len() < 2 {
+            continue;
+        }
+
+        for (i, &a) in anntenas.iter().enumerate() {
+            for &b in &anntenas[i + 1..] {
+                for p in [a * 2 - b, b * 2 - a] {
+                    if grid.in_bounds(p) {
+                        antinodes.insert(p);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(antinodes.len().into())
+}
+
+fn part2(grid: &Grid<char>) -> Result<Answer> {
+    let freq = antennas(grid);
+
+    let mut antinodes = HashSet::new();
+    for anntenas in freq.values() {
+        if anntenas.len() < 2 {
+            continue;
+        }
+
+        for (i, &a) in anntenas.iter().enumerate() {
+            for &b in &anntenas[i + 1..] {
+                let d = b - a;
+                let step = gcd(d.row, d.col);
+                let d_step = Point::new(d.row / step, d.col / step);
+
+                let mut p = a;
+                while grid.in_bounds(p + d_step) {
+                    p += d_step;
+                    antinodes.insert(p);
+                }
+
+                while grid.in_bounds(p - d_step) {
+                    p -= d_step;
+                    antinodes.insert(p);
+                }
+            }
+        }
+    }
+
+    Ok(antinodes.len().into())
+}
+
+fn antennas(grid: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    let mut freq: HashMap<char, Vec<Point>> = HashMap::new();
+    for (p, c) in grid.iter() {
+        if c.is_alphanumeric() {
+            freq.entry(*c).or_default().push(p);
+        }
+    }
+    freq
+}
+
+fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a.abs()
+}
@@ -0,0 +1,95 @@
+use aoc_common::{Answer, Grid, Params, Point, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Grid<u32>> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<u32>, _params: &Params) -> Result<Answer> {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<u32>, _params: &Params) -> Result<Answer> {
+        part2(grid)
+    }
+}
+
+fn part1(grid: &Grid<u32>) -> Result<Answer> {
+    let mut sum = 0;
+    for trailhead in grid.positions(|h| *h == 0) {
+        let found = dfs(grid, trailhead);
+        // println!("found trails: {}", found.len());
+        sum += found.len();
+    }
+
+    Ok(sum.into())
+}
+
+/// The topographic map, one height per cell.
+pub fn parse(input: &str) -> Result<Grid<u32>> {
+    Grid::parse_with(input, "a height digit", |c| c.to_digit(10))
+}
+
+fn dfs(grid: &Grid<u32>, start: Point) -> HashSet<Point> {
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut stack: Vec<Point> = vec![start];
+
+    let mut found = HashSet::new();
+    while let Some(p) = stack.pop() {
+        if !visited.insert(p) {
+            continue;
+        }
+
+        if grid[p] == 9 {
+            // println!("Found trailhead at {}", p);
+            found.insert(p);
+        }
+
+        for n in grid.neighbors4(p) {
+            if grid[n] == grid[p] + 1 {
+                stack.push(n);
+            }
+        }
+    }
+
+    found
+}
+
+fn part2(grid: &Grid<u32>) -> Result<Answer> {
+    let mut memo = Grid::new(grid.rows(), grid.cols(), 0);
+
+    let mut sum = 0;
+    for trailhead in grid.positions(|h| *h == 0) {
+        let count = count_trails(grid, trailhead, &mut memo);
+        // println!("count: {}", count);
+        sum += count;
+    }
+
+    Ok(sum.into())
+}
+
+fn count_trails(grid: &Grid<u32>, p: Point, memo: &mut Grid<i64>) -> i64 {
+    if memo[p] != 0 {
+        return memo[p];
+    }
+
+    if grid[p] == 9 {
+        memo[p] = 1;
+        return 1;
+    }
+
+    let mut count = 0;
+    for n in grid.neighbors4(p) {
+        if grid[n] == grid[p] + 1 {
+            count += count_trails(grid, n, memo);
+        }
+    }
+
+    memo[p] = count;
+    count
+}
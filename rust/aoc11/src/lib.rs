@@ -0,0 +1,105 @@
+use aoc_common::{debug, parse, Answer, Param, Params, Result, Solution};
+use std::collections::HashMap;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<i64>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part1_blinks",
+            default: 25,
+            min: 0,
+            max: i64::MAX,
+            help: "Times the stones blink in part 1",
+        },
+        Param {
+            name: "part2_blinks",
+            default: 75,
+            min: 0,
+            max: i64::MAX,
+            help: "Times the stones blink in part 2",
+        },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>> {
+        parse(input)
+    }
+
+    fn part1(&self, stones: &Vec<i64>, params: &Params) -> Result<Answer> {
+        part1(stones, params.get("part1_blinks")?)
+    }
+
+    fn part2(&self, stones: &Vec<i64>, params: &Params) -> Result<Answer> {
+        part2(stones, params.get("part2_blinks")?)
+    }
+}
+
+fn part1(stones: &[i64], blinks: i64) -> Result<Answer> {
+    let mut memo: HashMap<(i64, i64), i64> = HashMap::new();
+
+    let mut sum = 0;
+    for stone in stones.iter() {
+        let count = count_stones(*stone, blinks, &mut memo);
+        sum += count;
+    }
+
+    Ok(sum.into())
+}
+
+fn part2(stones: &[i64], blinks: i64) -> Result<Answer> {
+    let mut memo: HashMap<(i64, i64), i64> = HashMap::new();
+
+    let mut sum = 0;
+    for stone in stones.iter() {
+        let count = count_stones(*stone, blinks, &mut memo);
+        sum += count;
+    }
+
+    debug!("{}", memo.len());
+    Ok(sum.into())
+}
+
+/// The numbers engraved on the stones, in a line.
+pub fn parse(input: &str) -> Result<Vec<i64>> {
+    let mut stones = vec![];
+    for mut line in parse::lines(input) {
+        line.skip_spaces();
+        while !line.is_empty() {
+            stones.push(line.number()?);
+            line.skip_spaces();
+        }
+    }
+    Ok(stones)
+}
+
+fn count_stones(stone: i64, blinks_remaining: i64, memo: &mut HashMap<(i64, i64), i64>) -> usize {
+    if blinks_remaining == 0 {
+        return 1;
+    }
+
+    if let Some(count) = memo.get(&(stone, blinks_remaining)) {
+        return *count as usize;
+    }
+
+    let result;
+    if stone == 0 {
+        result = count_stones(1, blinks_remaining - 1, memo);
+    } else if stone.to_string().len().is_multiple_of(2) {
+        let first_half = &stone.to_string()[0..stone.to_string().len() / 2]
+            .parse::<i64>()
+            .unwrap();
+        let second_half = &stone.to_string()[stone.to_string().len() / 2..]
+            .parse::<i64>()
+            .unwrap();
+        result = count_stones(*first_half, blinks_remaining - 1, memo)
+            + count_stones(*second_half, blinks_remaining - 1, memo);
+    } else {
+        result = count_stones(stone * 2024, blinks_remaining - 1, memo);
+    }
+
+    memo.insert((stone, blinks_remaining), result as i64);
+
+    result
+}
@@ -0,0 +1,181 @@
+use aoc_common::{debug, Answer, Direction, Grid, Params, Point, Result, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>, _params: &Params) -> Result<Answer> {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<char>, _params: &Params) -> Result<Answer> {
+        part2(grid)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Group {
+    char: char,
+    area: HashSet<Point>,
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input)
+}
+
+fn part1(grid: &Grid<char>) -> Result<Answer> {
+    // group aread of same char
+    let groups = group_areas(grid);
+
+    // println!("{:?}", groups.len());
+    // println!("{:?}", groups);
+
+    let mut perimete_pairs = vec![];
+    // for each group count the permieter
+    for group in groups.iter() {
+        let mut perimeter = 0;
+
+        for p in group.area.iter() {
+            perimeter += p.neighbors4().filter(|n| !group.area.contains(n)).count();
+        }
+
+        // println!("{:?} {:?}", group.char, perimeter);
+        perimete_pairs.push((group.area.len(), perimeter));
+    }
+
+    let mut perimeter = 0;
+    for (area, p) in perimete_pairs.iter() {
+        perimeter += area * p;
+    }
+
+    Ok(perimeter.into())
+}
+
+fn part2(grid: &Grid<char>) -> Result<Answer> {
+    let mut seen: HashSet<Point> = HashSet::new();
+    let mut p1 = 0;
+    let mut p2 = 0;
+
+    for start in grid.points() {
+        if seen.contains(&start) {
+            continue;
+        }
+
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+
+        let mut area = 0;
+        let mut perimeter = 0;
+        let mut perim_map: HashMap<Direction, HashSet<Point>> = HashMap::new();
+
+        while let Some(p) = queue.pop_front() {
+            if !seen.insert(p) {
+                continue;
+            }
+
+            area += 1;
+
+            for dir in Direction::ALL {
+                let n = p.step(dir);
+                if grid.get(n) == Some(&grid[p]) {
+                    queue.push_back(n);
+                } else {
+                    perimeter += 1;
+                    perim_map.entry(dir).or_default().insert(p);
+                }
+            }
+        }
+
+        let mut sides = 0;
+
+        for cells in perim_map.values() {
+            let mut seen_perim: HashSet<Point> = HashSet::new();
+
+            for &cell in cells {
+                if seen_perim.contains(&cell) {
+                    continue;
+                }
+
+                sides += 1;
+                let mut queue = VecDeque::new();
+                queue.push_back(cell);
+
+                while let Some(p) = queue.pop_front() {
+                    if !seen_perim.insert(p) {
+                        continue;
+                    }
+
+                    for n in p.neighbors4() {
+                        if cells.contains(&n) {
+                            queue.push_back(n);
+                        }
+                    }
+                }
+            }
+        }
+
+        p1 += area * perimeter;
+        p2 += area * sides;
+    }
+
+    debug!("{:?}", p1);
+    Ok(p2.into())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct State {
+    char: char,
+    current: Point,
+}
+
+fn group_areas(grid: &Grid<char>) -> Vec<Group> {
+    let mut visited = HashSet::new();
+
+    let mut groups = vec![];
+
+    for start in grid.points() {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let state = State {
+            char: grid[start],
+            current: start,
+        };
+        let mut stack = vec![state];
+
+        let mut area = HashSet::new();
+        area.insert(start);
+
+        while let Some(state) = stack.pop() {
+            visited.insert(state.current);
+
+            for n in grid.neighbors4(state.current) {
+                if visited.contains(&n) {
+                    continue;
+                }
+
+                if grid[n] == state.char {
+                    area.insert(n);
+                    stack.push(State {
+                        char: state.char,
+                        current: n,
+                    });
+                }
+            }
+        }
+
+        groups.push(Group {
+            char: grid[start],
+            area,
+        });
+    }
+
+    groups
+}
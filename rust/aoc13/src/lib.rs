@@ -0,0 +1,212 @@
+use aoc_common::{parse, Answer, Cursor, Params, Result, Solution};
+use std::collections::HashMap;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Game>> {
+        parse(input)
+    }
+
+    fn part1(&self, games: &Vec<Game>, _params: &Params) -> Result<Answer> {
+        part1(games)
+    }
+
+    fn part2(&self, games: &Vec<Game>, _params: &Params) -> Result<Answer> {
+        part2(games)
+    }
+}
+
+/// One claw machine: how far each button moves the claw, and where the prize is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Game {
+    pub button_a: (i64, i64),
+    pub button_b: (i64, i64),
+    pub prize: (i64, i64),
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>> {
+    parse::blocks(input).into_iter().map(Game::parse).collect()
+}
+
+impl Game {
+    fn parse(mut block: Cursor) -> Result<Game> {
+        block.expect("Button A: X+")?;
+        let ax = block.number()?;
+        block.expect(", Y+")?;
+        let ay = block.number()?;
+        block.expect("\nButton B: X+")?;
+        let bx = block.number()?;
+        block.expect(", Y+")?;
+        let by = block.number()?;
+        block.expect("\nPrize: X=")?;
+        let px = block.number()?;
+        block.expect(", Y=")?;
+        let py = block.number()?;
+        block.end()?;
+
+        Ok(Game {
+            button_a: (ax, ay),
+            button_b: (bx, by),
+            prize: (px, py),
+        })
+    }
+
+    fn calc_tokens_with_scale(&self, scale: i64) -> Option<i64> {
+        let a = self.button_a;
+        let b = self.button_b;
+        let prize = (self.prize.0 + scale, self.prize.1 + scale);
+
+        let n = (a.0 * prize.1 - a.1 * prize.0) / (a.0 * b.1 - a.1 * b.0);
+        let m = (prize.0 - b.0 * n) / a.0;
+
+        if (a.0 * m + b.0 * n, a.1 * m + b.1 * n) == prize {
+            Some(3 * m + n)
+        } else {
+            None
+        }
+    }
+
+    fn calc_scaled(&self) -> Option<i64> {
+        self.calc_tokens_with_scale(10_000_000_000_000)
+    }
+}
+
+// Sample input:
+// Button A: X+94, Y+34
+// Button B: X+22, Y+67
+// Prize: X=8400, Y=5400
+fn part1(games: &[Game]) -> Result<Answer> {
+    // println!("{:?}", games);
+
+    let mut memo = HashMap::new();
+    let mut sum = 0;
+    for game in games.iter() {
+        let min_taps = min_taps(game.prize, game, &mut memo, 0, 0, PRESS_LIMIT);
+
+        if min_taps == i64::MAX {
+            // println!("No solution found");
+        } else {
+            // println!("Min taps: {}", min_taps);
+            sum += min_taps;
+        }
+    }
+
+    Ok(sum.into())
+}
+
+const COST_A: i64 = 3;
+const COST_B: i64 = 1;
+const PRESS_LIMIT: i64 = 100;
+
+fn min_taps(
+    p: (i64, i64),
+    game: &Game,
+    memo: &mut HashMap<(i64, i64, i64, i64), i64>,
+    a_count: i64,
+    b_count: i64,
+    limit: i64,
+) -> i64 {
+    if p == (0, 0) {
+        return 0;
+    }
+
+    if a_count > limit || b_count > limit {
+        return i64::MAX;
+    }
+
+    if p.0 < 0 || p.1 < 0 {
+        return i64::MAX;
+    }
+
+    if p.0 > game.prize.0 || p.1 > game.prize.1 {
+        return i64::MAX;
+    }
+
+    if let Some(&result) = memo.get(&(p.0, p.1, a_count, b_count)) {
+        return result;
+    }
+
+    let mut cost_options = vec![];
+
+    let prev_x_a = p.0 - game.button_a.0;
+    let prev_y_a = p.1 - game.button_a.1;
+
+    // println!("A: {} {} {} {}", p.0, p.1, prev_x_a, prev_y_a);
+    if prev_x_a >= 0 && prev_y_a >= 0 {
+        let cost_from_a = min_taps(
+            (prev_x_a, prev_y_a),
+            game,
+            memo,
+            a_count + 1,
+            b_count,
+            limit,
+        );
+        if cost_from_a != i64::MAX {
+            cost_options.push(cost_from_a + COST_A)
+        }
+    }
+
+    let prev_x_b = p.0 - game.button_b.0;
+    let prev_y_b = p.1 - game.button_b.1;
+
+    // println!("B: {} {} {} {}", p.0, p.1, prev_x_b, prev_y_b);
+    if prev_x_b >= 0 && prev_y_b >= 0 {
+        let cost_from_b = min_taps(
+            (prev_x_b, prev_y_b),
+            game,
+            memo,
+            a_count,
+            b_count + 1,
+            limit,
+        );
+        if cost_from_b != i64::MAX {
+            cost_options.push(cost_from_b + COST_B)
+        }
+    }
+
+    if cost_options.is_empty() {
+        memo.insert((p.0, p.1, a_count, b_count), i64::MAX);
+        return i64::MAX;
+    }
+
+    // println!("{:?}", cost_options);
+    let best_cost = cost_options.iter().min().unwrap_or(&0);
+    memo.insert((p.0, p.1, a_count, b_count), *best_cost);
+    *best_cost
+}
+
+fn part2(games: &[Game]) -> Result<Answer> {
+    let mut sum = 0;
+    for game in games.iter() {
+        if let Some(min_taps) = game.calc_scaled() {
+            sum += min_taps;
+        }
+    }
+
+    Ok(sum.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_buttons_say_where() {
+        let input = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67 Y+21
+Prize: X=12748, Y=12176
+";
+        assert_eq!(
+            parse(input).unwrap_err().to_string(),
+            "line 6, column 15: expected \", Y+\", found ' '"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use aoc_common::{debug, parse, Answer, Cursor, Param, Params, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "width",
+            default: 101,
+            min: 1,
+            max: i64::MAX,
+            help: "Width of the bathroom in tiles",
+        },
+        Param {
+            name: "height",
+            default: 103,
+            min: 1,
+            max: i64::MAX,
+            help: "Height of the bathroom in tiles",
+        },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Vec<Robot>> {
+        parse(input)
+    }
+
+    fn part1(&self, robots: &Vec<Robot>, params: &Params) -> Result<Answer> {
+        part1(robots, params.get("width")?, params.get("height")?)
+    }
+
+    fn part2(&self, robots: &Vec<Robot>, params: &Params) -> Result<Answer> {
+        part2(robots, params.get("width")?, params.get("height")?)
+    }
+}
+
+/// A robot's starting `(x, y)` tile and how far it moves each second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Robot {
+    pub position: (i32, i32),
+    pub velocity: (i32, i32),
+}
+
+pub fn parse(input: &str) -> Result<Vec<Robot>> {
+    parse::lines(input).map(Robot::parse).collect()
+}
+
+impl Robot {
+    fn parse(mut line: Cursor) -> Result<Robot> {
+        line.expect("p=")?;
+        let px = line.number()?;
+        line.expect(",")?;
+        let py = line.number()?;
+        line.expect(" v=")?;
+        let vx = line.number()?;
+        line.expect(",")?;
+        let vy = line.number()?;
+        line.end()?;
+
+        Ok(Robot {
+            position: (px, py),
+            velocity: (vx, vy),
+        })
+    }
+}
+
+// p=position v=velocity
+// p=0,4 v=3,-3
+// p=6,3 v=-1,-3
+// p=10,3 v=-1,2
+// p=2,0 v=2,-1
+// p=0,0 v=1,3
+// p=3,0 v=-2,-2
+// p=7,6 v=-1,-3
+// p=3,0 v=-1,-2
+// p=9,3 v=2,3
+// p=7,3 v=-1,2
+// p=2,4 v=2,-3
+// p=9,5 v=-3,-3
+fn part1(robots: &[Robot], width: i32, height: i32) -> Result<Answer> {
+    let mut robots = robots.to_vec();
+
+    for _ in 0..100 {
+        for robot in robots.iter_mut() {
+            robot.position.0 = (robot.position.0 + robot.velocity.0).rem_euclid(width);
+            robot.position.1 = (robot.position.1 + robot.velocity.1).rem_euclid(height);
+        }
+    }
+
+    let x_midpoint = width / 2;
+    let y_midpoint = height / 2;
+    let upper_left_quadrant_x = (0, x_midpoint);
+    let upper_left_quadrant_y = (0, y_midpoint);
+
+    let upper_right_quadrant_x = (x_midpoint, width);
+    let upper_right_quadrant_y = (0, y_midpoint);
+
+    let lower_left_quadrant_x = (0, x_midpoint);
+    let lower_left_quadrant_y = (y_midpoint, height);
+
+    let lower_right_quadrant_x = (x_midpoint, width);
+    let lower_right_quadrant_y = (y_midpoint, height);
+
+    let mut quadrant_counts = [0; 4];
+
+    debug!("midpoint: ({}, {})", x_midpoint, y_midpoint);
+    for r in &robots {
+        debug!("{:?}", r.position);
+        if r.position.0 == x_midpoint || r.position.1 == y_midpoint {
+            debug!("midpoint");
+            continue;
+        }
+
+        // upper left quadrant
+        if upper_left_quadrant_x.0 <= r.position.0
+            && r.position.0 < upper_left_quadrant_x.1
+            && upper_left_quadrant_y.0 <= r.position.1
+            && r.position.1 < upper_left_quadrant_y.1
+        {
+            quadrant_counts[0] += 1;
+        } else if upper_right_quadrant_x.0 <= r.position.0
+            && r.position.0 < upper_right_quadrant_x.1
+            && upper_right_quadrant_y.0 <= r.position.1
+            && r.position.1 < upper_right_quadrant_y.1
+        {
+            quadrant_counts[1] += 1;
+        } else if lower_left_quadrant_x.0 <= r.position.0
+            && r.position.0 < lower_left_quadrant_x.1
+            && lower_left_quadrant_y.0 <= r.position.1
+            && r.position.1 < lower_left_quadrant_y.1
+        {
+            quadrant_counts[2] += 1;
+        } else if lower_right_quadrant_x.0 <= r.position.0
+            && r.position.0 < lower_right_quadrant_x.1
+            && lower_right_quadrant_y.0 <= r.position.1
+            && r.position.1 < lower_right_quadrant_y.1
+        {
+            quadrant_counts[3] += 1;
+        }
+    }
+
+    debug!("{:?}", quadrant_counts);
+
+    let sum = quadrant_counts.iter().product::<i32>();
+    Ok(sum.into())
+}
+
+fn print_robots(robots: &[Robot], width: i32, height: i32) {
+    let grid: HashSet<(i32, i32)> = robots.iter().map(|r| r.position).collect();
+
+    let mut picture = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            picture.push(if grid.contains(&(x, y)) { '#' } else { '.' });
+        }
+        picture.push('\n');
+    }
+    debug!("{}", picture);
+}
+
+fn part2(robots: &[Robot], width: i32, height: i32) -> Result<Answer> {
+    let mut robots = robots.to_vec();
+
+    // The picture shows up the first time no two robots share a tile.
+    for s in 1..=width * height {
+        for robot in robots.iter_mut() {
+            robot.position.0 = (robot.position.0 + robot.velocity.0).rem_euclid(width);
+            robot.position.1 = (robot.position.1 + robot.velocity.1).rem_euclid(height);
+        }
+
+        let tiles: HashSet<(i32, i32)> = robots.iter().map(|r| r.position).collect();
+        if tiles.len() == robots.len() {
+            debug!("after: {}", s);
+            print_robots(&robots, width, height);
+            return Ok(s.into());
+        }
+    }
+
+    Err("the robots never spread out into a picture".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_robots_say_where() {
+        let input = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3v=-1,2\n";
+        assert_eq!(
+            parse(input).unwrap_err().to_string(),
+            "line 3, column 7: expected \" v=\", found 'v'"
+        );
+        assert_eq!(
+            parse("p=2,x v=2,-1\n").unwrap_err().to_string(),
+            "line 1, column 5: expected a number, found 'x'"
+        );
+    }
+}
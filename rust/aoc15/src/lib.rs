@@ -0,0 +1,172 @@
+use aoc_common::{debug, parse, Answer, Cursor, Direction, Grid, Params, Point, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+
+    fn parse(&self, input: &str) -> Result<Warehouse> {
+        parse(input)
+    }
+
+    fn part1(&self, warehouse: &Warehouse, _params: &Params) -> Result<Answer> {
+        part1(warehouse)
+    }
+
+    fn part2(&self, warehouse: &Warehouse, _params: &Params) -> Result<Answer> {
+        part2(warehouse)
+    }
+}
+
+/// The warehouse map, with the robot as `@`, and the robot's planned moves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warehouse {
+    pub grid: Grid<char>,
+    pub moves: Vec<Direction>,
+}
+
+pub fn parse(input: &str) -> Result<Warehouse> {
+    let [grid, moves] = parse::sections(input)?;
+    Ok(Warehouse {
+        grid: parse_warehouse(grid)?,
+        moves: parse_moves(moves)?,
+    })
+}
+
+fn parse_warehouse(section: Cursor) -> Result<Grid<char>> {
+    Grid::parse_with(section, "one of '#', '.', 'O' or '@'", |c| {
+        matches!(c, '#' | '.' | 'O' | '@').then_some(c)
+    })
+}
+
+fn parse_moves(mut section: Cursor) -> Result<Vec<Direction>> {
+    let mut moves = vec![];
+    while let Some(c) = section.peek() {
+        if !c.is_whitespace() {
+            moves.push(Direction::from_char(c).ok_or_else(|| section.error("a move"))?);
+        }
+        section.bump();
+    }
+    Ok(moves)
+}
+
+fn part1(warehouse: &Warehouse) -> Result<Answer> {
+    let mut grid = warehouse.grid.clone();
+
+    let mut pos = grid.find(&'@').ok_or("no robot in warehouse")?;
+    grid[pos] = '.';
+    let walls: HashSet<Point> = grid.positions(|c| *c == '#').collect();
+    let mut boxes: HashSet<Point> = grid.positions(|c| *c == 'O').collect();
+
+    for &dir in &warehouse.moves {
+        move_box(&grid, &mut pos, &walls, &mut boxes, dir);
+    }
+
+    // print_grid(&grid, pos, &boxes, &walls);
+
+    let mut sum = 0;
+    for b in boxes {
+        sum += 100 * b.row + b.col;
+    }
+
+    Ok(sum.into())
+}
+
+fn part2(warehouse: &Warehouse) -> Result<Answer> {
+    // expand grid
+    let rows = (0..warehouse.grid.rows())
+        .map(|r| {
+            warehouse
+                .grid
+                .row(r)
+                .iter()
+                .flat_map(|c| match c {
+                    '#' => ['#', '#'],
+                    'O' => ['[', ']'],
+                    '@' => ['@', '.'],
+                    _ => ['.', '.'],
+                })
+                .collect()
+        })
+        .collect();
+    let mut grid = Grid::from_rows(rows)?;
+
+    for p in grid.positions(|c| *c == '[') {
+        debug!("O at {}", p);
+    }
+
+    debug!("width: {}", grid.cols());
+    debug!("height: {}", grid.rows());
+
+    debug!("{}", grid);
+
+    let pos = grid.find(&'@').ok_or("no robot in warehouse")?;
+    grid[pos] = '.';
+    let walls: HashSet<Point> = grid.positions(|c| *c == '#').collect();
+    let boxes: HashSet<Point> = grid.positions(|c| *c == 'O').collect();
+
+    // for dir in moves {
+    //     move_box(&grid, &mut pos, &walls, &mut boxes, dir);
+    // }
+
+    print_grid(&grid, pos, &boxes, &walls);
+
+    // let mut sum = 0;
+    // for b in boxes {
+    //     sum += 100 * b.row + b.col;
+    // }
+
+    // Ok(sum.into())
+    Err("moving wide boxes is not implemented".into())
+}
+
+fn is_empty_cell(pos: Point, walls: &HashSet<Point>, boxes: &HashSet<Point>) -> bool {
+    !walls.contains(&pos) && !boxes.contains(&pos)
+}
+
+fn print_grid(grid: &Grid<char>, pos: Point, boxes: &HashSet<Point>, walls: &HashSet<Point>) {
+    let mut display = grid.map(|_| '.');
+    for w in walls {
+        display[*w] = '#';
+    }
+    for b in boxes {
+        display[*b] = 'O';
+    }
+    display[pos] = '@';
+
+    debug!("{}", display);
+}
+
+fn move_box(
+    grid: &Grid<char>,
+    pos: &mut Point,
+    walls: &HashSet<Point>,
+    boxes: &mut HashSet<Point>,
+    dir: Direction,
+) {
+    let next_pos = pos.step(dir);
+
+    if grid.in_bounds(next_pos) && is_empty_cell(next_pos, walls, boxes) {
+        // Move player to empty cell
+        *pos = next_pos;
+    } else if grid.in_bounds(next_pos) && boxes.contains(&next_pos) {
+        // Handle box movement
+        let mut next_box_pos = next_pos;
+        loop {
+            next_box_pos = next_box_pos.step(dir);
+
+            if !grid.in_bounds(next_box_pos) || walls.contains(&next_box_pos) {
+                break;
+            }
+
+            if !boxes.contains(&next_box_pos) {
+                // Found an empty spot for the box
+                boxes.remove(&next_pos);
+                boxes.insert(next_box_pos);
+                *pos = next_pos;
+                break;
+            }
+        }
+    }
+}
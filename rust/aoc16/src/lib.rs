@@ -0,0 +1,219 @@
+use aoc_common::{debug, Answer, Direction, Grid, Params, Point, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(&self, input: &str) -> Result<Maze> {
+        parse(input)
+    }
+
+    fn part1(&self, maze: &Maze, _params: &Params) -> Result<Answer> {
+        part1(maze)
+    }
+
+    fn part2(&self, maze: &Maze, _params: &Params) -> Result<Answer> {
+        part2(maze)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+/// The maze, with the reindeer's start and the end tile it is heading for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Maze {
+    pub grid: Grid<Tile>,
+    pub start: Point,
+    pub end: Point,
+}
+
+pub fn parse(input: &str) -> Result<Maze> {
+    let grid = Grid::parse_with(input, "one of '.', '#', 'S' or 'E'", |c| match c {
+        'S' => Some(Tile::Start),
+        'E' => Some(Tile::End),
+        '#' => Some(Tile::Wall),
+        '.' => Some(Tile::Empty),
+        _ => None,
+    })?;
+    let start = grid.find(&Tile::Start).ok_or("maze has no start")?;
+    let end = grid.find(&Tile::End).ok_or("maze has no end")?;
+
+    Ok(Maze { grid, start, end })
+}
+
+fn part1(maze: &Maze) -> Result<Answer> {
+    let (start, end) = (maze.start, maze.end);
+    let maze = &maze.grid;
+
+    debug!("{:?}", start);
+    debug!("{:?}", end);
+
+    let score = dfs(maze, start, end);
+    print_maze(maze);
+
+    Ok(score.into())
+}
+
+fn part2(maze: &Maze) -> Result<Answer> {
+    let (start, end) = (maze.start, maze.end);
+    let maze = &maze.grid;
+
+    debug!("{:?}", start);
+    debug!("{:?}", end);
+
+    let start_state = State {
+        pos: start,
+        dir: Direction::Right,
+        score: 0,
+        path: vec![],
+    };
+
+    let Some((paths, _)) = pathfinding::prelude::astar_bag_collect(
+        &start_state,
+        |state| {
+            let neighbors = [
+                (State::new(state.pos.step(state.dir), state.dir, 1), 1),
+                (State::new(state.pos, state.dir.rotate_right(), 1001), 1001),
+                (State::new(state.pos, state.dir.rotate_left(), 1001), 1001),
+            ];
+
+            let mut successors = vec![];
+            for (n, cost) in neighbors {
+                match maze.get(n.pos) {
+                    Some(Tile::Empty | Tile::Start | Tile::End) => {
+                        successors.push((n, cost));
+                    }
+                    Some(Tile::Wall) | None => {
+                        continue;
+                    }
+                }
+            }
+
+            successors
+        },
+        |state| state.pos.manhattan(end),
+        |state| state.pos == end,
+    ) else {
+        return Err("no path found".into());
+    };
+
+    let tiles: HashSet<Point> = paths.iter().flat_map(|p| p.iter().map(|s| s.pos)).collect();
+    print_maze_path(maze, &tiles);
+    Ok(tiles.len().into())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    pos: Point,
+    dir: Direction,
+    score: i64,
+    path: Vec<Point>,
+}
+
+impl State {
+    fn new(pos: Point, dir: Direction, score: i64) -> Self {
+        Self {
+            pos,
+            dir,
+            score,
+            path: vec![],
+        }
+    }
+}
+
+fn dfs(maze: &Grid<Tile>, start: Point, end: Point) -> i64 {
+    let mut visited = HashSet::new();
+
+    let state = State {
+        pos: start,
+        dir: Direction::Right,
+        score: 0,
+        path: vec![],
+    };
+
+    let mut stack = vec![state];
+    while let Some(state) = min_score(&mut stack) {
+        if state.pos == end {
+            debug!("found end: {:?}", state);
+            return state.score;
+        }
+
+        if !visited.insert((state.pos, state.dir)) {
+            continue;
+        }
+
+        let neighbors = [
+            (state.pos.step(state.dir), state.dir, 1),
+            (state.pos, state.dir.rotate_right(), 1000),
+            (state.pos, state.dir.rotate_left(), 1000),
+        ];
+
+        for (pos, dir, cost) in neighbors {
+            if visited.contains(&(pos, dir)) {
+                continue;
+            }
+
+            match maze.get(pos) {
+                Some(Tile::Empty | Tile::Start | Tile::End) => {
+                    stack.push(State {
+                        pos,
+                        dir,
+                        score: state.score + cost,
+                        path: state.path.clone(),
+                    });
+                }
+                Some(Tile::Wall) | None => {
+                    continue;
+                }
+            }
+        }
+    }
+
+    0
+}
+
+fn print_maze(maze: &Grid<Tile>) {
+    debug!("{}", maze.map(tile_char));
+}
+
+fn print_maze_path(maze: &Grid<Tile>, path: &HashSet<Point>) {
+    let mut display = maze.map(tile_char);
+    for p in path {
+        display[*p] = 'O';
+    }
+    debug!("{}", display);
+}
+
+fn tile_char(tile: &Tile) -> char {
+    match tile {
+        Tile::Empty => '.',
+        Tile::Wall => '#',
+        Tile::Start => 'S',
+        Tile::End => 'E',
+    }
+}
+
+fn min_score(stack: &mut Vec<State>) -> Option<State> {
+    if stack.is_empty() {
+        return None;
+    }
+
+    let mut min = i64::MAX;
+    let mut index = usize::MAX;
+    for (i, s) in stack.iter().enumerate() {
+        if s.score < min {
+            min = s.score;
+            index = i;
+        }
+    }
+
+    Some(stack.remove(index))
+}
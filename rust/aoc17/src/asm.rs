@@ -0,0 +1,248 @@
+//! A readable syntax for 3-bit programs, one instruction per line. Combo
+//! operands are shown resolved, so `6,5` reads as `bdv A>>B`:
+//!
+//! ```text
+//! adv A>>3     bxl B^5     bst A%8     jnz 0
+//! bxc B^C      out B%8     bdv A>>B    cdv A>>C
+//! ```
+//!
+//! `bxc` ignores its operand. A non-zero one is kept as `bxc B^C #4`, and a
+//! pair with the reserved combo operand 7, which may be data that is never
+//! run, is kept as `.raw 2,7`, so that any program survives a round trip.
+//! `;` starts a comment.
+
+use std::fmt::{self, Display};
+
+use aoc_common::{parse, Cursor, Result};
+
+use crate::vm::{Combo, Fault, Instruction, Literal, Register};
+use crate::Program;
+
+impl Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Register::A => "A",
+            Register::B => "B",
+            Register::C => "C",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Combo::Constant(n) => write!(f, "{}", n),
+            Combo::Register(r) => write!(f, "{}", r),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Adv(x) => write!(f, "adv A>>{}", x),
+            Instruction::Bxl(Literal(n)) => write!(f, "bxl B^{}", n),
+            Instruction::Bst(x) => write!(f, "bst {}%8", x),
+            Instruction::Jnz(Literal(n)) => write!(f, "jnz {}", n),
+            Instruction::Bxc(Literal(0)) => write!(f, "bxc B^C"),
+            Instruction::Bxc(Literal(n)) => write!(f, "bxc B^C #{}", n),
+            Instruction::Out(x) => write!(f, "out {}%8", x),
+            Instruction::Bdv(x) => write!(f, "bdv A>>{}", x),
+            Instruction::Cdv(x) => write!(f, "cdv A>>{}", x),
+        }
+    }
+}
+
+/// The instructions of `program`, one per line.
+pub fn disassemble(program: &[u8]) -> Result<String> {
+    if !program.len().is_multiple_of(2) {
+        return Err(Fault::OddLength { len: program.len() }.into());
+    }
+
+    let mut text = String::new();
+    for pair in program.chunks(2) {
+        text += &format!("{}\n", pair_text(pair[0], pair[1]));
+    }
+    Ok(text)
+}
+
+/// One pair of program values as an instruction, or as `.raw` if it is not
+/// a valid one.
+pub fn pair_text(opcode: u8, operand: u8) -> String {
+    match Instruction::decode(opcode, operand) {
+        Some(instruction) => instruction.to_string(),
+        None => format!(".raw {},{}", opcode, operand),
+    }
+}
+
+/// The program written by `text` in the syntax [`disassemble`] produces.
+pub fn assemble(text: &str) -> Result<Program> {
+    let mut program = vec![];
+    for mut line in parse::lines(text) {
+        line.skip_spaces();
+        if line.eat(";") {
+            continue;
+        }
+        let pair = if line.eat(".raw") {
+            line.expect(" ")?;
+            line.skip_spaces();
+            let opcode = literal(&mut line)?;
+            line.expect(",")?;
+            [opcode.0, literal(&mut line)?.0]
+        } else {
+            instruction(&mut line)?.encode()
+        };
+        line.skip_spaces();
+        if line.eat(";") {
+            line.take_while(|_| true);
+        }
+        line.end()?;
+        program.extend(pair);
+    }
+    Ok(program)
+}
+
+/// The comma-separated form of `program`, as in the puzzle input.
+pub fn to_comma(program: &[u8]) -> String {
+    let values: Vec<String> = program.iter().map(|v| v.to_string()).collect();
+    values.join(",")
+}
+
+fn instruction(line: &mut Cursor) -> Result<Instruction> {
+    let start = line.clone();
+    let mnemonic = line.take_while(|c| c.is_ascii_alphabetic());
+    line.expect(" ")?;
+    line.skip_spaces();
+
+    Ok(match mnemonic {
+        "adv" => Instruction::Adv(shift(line)?),
+        "bxl" => {
+            line.expect("B^")?;
+            Instruction::Bxl(literal(line)?)
+        }
+        "bst" => Instruction::Bst(modulo(line)?),
+        "jnz" => Instruction::Jnz(literal(line)?),
+        "bxc" => {
+            line.expect("B^C")?;
+            line.skip_spaces();
+            if line.eat("#") {
+                Instruction::Bxc(literal(line)?)
+            } else {
+                Instruction::Bxc(Literal(0))
+            }
+        }
+        "out" => Instruction::Out(modulo(line)?),
+        "bdv" => Instruction::Bdv(shift(line)?),
+        "cdv" => Instruction::Cdv(shift(line)?),
+        _ => return Err(start.error("an instruction such as adv or out").into()),
+    })
+}
+
+/// `A>>x`
+fn shift(line: &mut Cursor) -> Result<Combo> {
+    line.expect("A>>")?;
+    combo(line)
+}
+
+/// `x%8`
+fn modulo(line: &mut Cursor) -> Result<Combo> {
+    let x = combo(line)?;
+    line.expect("%8")?;
+    Ok(x)
+}
+
+fn combo(line: &mut Cursor) -> Result<Combo> {
+    let start = line.clone();
+    let operand = match line.bump() {
+        Some('A') => 4,
+        Some('B') => 5,
+        Some('C') => 6,
+        Some(c @ '0'..='3') => c as u8 - b'0',
+        _ => return Err(start.error("0-3, A, B or C").into()),
+    };
+    Ok(Combo::decode(operand).expect("combo operands 0-6 decode"))
+}
+
+fn literal(line: &mut Cursor) -> Result<Literal> {
+    let start = line.clone();
+    match line.digit()? {
+        n @ 0..=7 => Ok(Literal(n as u8)),
+        _ => Err(start.error("a 3-bit number").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8] = &[2, 4, 1, 6, 7, 5, 4, 6, 1, 4, 5, 5, 0, 3, 3, 0];
+
+    const LISTING: &str = "\
+bst A%8
+bxl B^6
+cdv A>>B
+bxc B^C #6
+bxl B^4
+out B%8
+adv A>>3
+jnz 0
+";
+
+    #[test]
+    fn disassembles_the_input() {
+        assert_eq!(disassemble(INPUT).unwrap(), LISTING);
+    }
+
+    #[test]
+    fn assembles_the_listing() {
+        assert_eq!(assemble(LISTING).unwrap(), INPUT);
+        assert_eq!(
+            to_comma(&assemble(LISTING).unwrap()),
+            "2,4,1,6,7,5,4,6,1,4,5,5,0,3,3,0"
+        );
+    }
+
+    #[test]
+    fn every_instruction_round_trips() {
+        for opcode in 0..8 {
+            for operand in 0..8 {
+                let program = [opcode, operand];
+                let text = disassemble(&program).unwrap();
+                assert_eq!(assemble(&text).unwrap(), program, "{}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn text_round_trips() {
+        let text = "adv A>>3\nbxl B^5\nbst C%8\njnz 0\nbxc B^C\nout 2%8\nbdv A>>A\ncdv A>>C\n";
+        assert_eq!(disassemble(&assemble(text).unwrap()).unwrap(), text);
+    }
+
+    #[test]
+    fn accepts_comments_and_reports_bad_lines() {
+        assert_eq!(assemble("; setup\nbst A%8 ; low bits\n").unwrap(), [2, 4]);
+
+        let e = assemble("bst A%8\nout D%8\n").unwrap_err().to_string();
+        assert_eq!(e, "line 2, column 5: expected 0-3, A, B or C, found 'D'");
+    }
+
+    #[test]
+    fn keeps_reserved_operands_as_raw_pairs() {
+        // The `out` with combo operand 7 is jumped over, so never faults.
+        let program = [3, 4, 5, 7, 5, 4];
+        let text = disassemble(&program).unwrap();
+        assert_eq!(text, "jnz 4\n.raw 5,7\nout A%8\n");
+        assert_eq!(assemble(&text).unwrap(), program);
+        assert_eq!(
+            assemble(".raw 8,1\n").unwrap_err().to_string(),
+            "line 1, column 6: expected a 3-bit number, found '8'"
+        );
+    }
+
+    #[test]
+    fn rejects_odd_lengths() {
+        assert!(disassemble(&[5]).is_err());
+    }
+}
@@ -0,0 +1,187 @@
+//! A faster way to run one program for many values of A. [`Compiled`]
+//! decodes every instruction once, resolves combo operands ahead of time,
+//! keeps the registers in locals while it runs, and can stop a run as soon as
+//! its output goes wrong.
+//!
+//! Most of the gain is from stopping early, so checking quine candidates
+//! speeds up far more than running whole programs. `aoc17 bench` measures
+//! both against the interpreter.
+
+use crate::vm::{self, Combo, Fault, Instruction, Literal, Registers};
+
+/// The registers while a compiled program runs. Slots 0-3 hold the
+/// constants and 4-6 hold A, B and C, so a combo operand is its own index.
+type Slots = [u64; 8];
+
+const A: usize = 4;
+const B: usize = 5;
+const C: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Adv(usize),
+    Bxl(u64),
+    Bst(usize),
+    Jnz(usize),
+    Bxc,
+    Out(usize),
+    Bdv(usize),
+    Cdv(usize),
+    /// Uses combo operand 7, which is only a fault if it is reached.
+    Reserved,
+}
+
+/// A program decoded for [`Compiled::run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compiled {
+    /// The instruction starting at each program position. A jump may land on
+    /// an odd position, so there is one for every position but the last.
+    ops: Vec<Op>,
+}
+
+impl Compiled {
+    pub fn new(program: &[u8]) -> Result<Compiled, Fault> {
+        vm::check(program)?;
+        let ops = program
+            .windows(2)
+            .map(|pair| {
+                let slot = |x: Combo| usize::from(x.encode());
+                match Instruction::decode(pair[0], pair[1]) {
+                    Some(Instruction::Adv(x)) => Op::Adv(slot(x)),
+                    Some(Instruction::Bxl(Literal(n))) => Op::Bxl(n.into()),
+                    Some(Instruction::Bst(x)) => Op::Bst(slot(x)),
+                    Some(Instruction::Jnz(Literal(n))) => Op::Jnz(n.into()),
+                    Some(Instruction::Bxc(_)) => Op::Bxc,
+                    Some(Instruction::Out(x)) => Op::Out(slot(x)),
+                    Some(Instruction::Bdv(x)) => Op::Bdv(slot(x)),
+                    Some(Instruction::Cdv(x)) => Op::Cdv(slot(x)),
+                    None => Op::Reserved,
+                }
+            })
+            .collect();
+        Ok(Compiled { ops })
+    }
+
+    /// Runs the program from the start until it halts, replacing the contents
+    /// of `output` with what it outputs. Behaves like [`crate::vm::Cpu::run`].
+    pub fn run(&self, registers: Registers, output: &mut Vec<u8>) -> Result<(), Fault> {
+        output.clear();
+        self.execute(registers, |value| {
+            output.push(value);
+            true
+        })
+    }
+
+    /// Whether the program outputs exactly `expected`. The run stops at the
+    /// first value that differs, which for most candidates in a search is
+    /// the first one.
+    pub fn outputs(&self, registers: Registers, expected: &[u8]) -> Result<bool, Fault> {
+        let mut count = 0;
+        let mut matched = true;
+        self.execute(registers, |value| {
+            matched = expected.get(count) == Some(&value);
+            count += 1;
+            matched
+        })?;
+        Ok(matched && count == expected.len())
+    }
+
+    /// Runs until the program halts or `out` returns false for a value.
+    fn execute(&self, registers: Registers, mut out: impl FnMut(u8) -> bool) -> Result<(), Fault> {
+        let mut r: Slots = [0, 1, 2, 3, registers.a, registers.b, registers.c, 0];
+        let mut pc = 0;
+        while let Some(&op) = self.ops.get(pc) {
+            match op {
+                Op::Adv(x) => r[A] = shr(r[A], r[x]),
+                Op::Bxl(n) => r[B] ^= n,
+                Op::Bst(x) => r[B] = r[x] % 8,
+                Op::Jnz(target) => {
+                    if r[A] != 0 {
+                        pc = target;
+                        continue;
+                    }
+                }
+                Op::Bxc => r[B] ^= r[C],
+                Op::Out(x) => {
+                    if !out((r[x] % 8) as u8) {
+                        break;
+                    }
+                }
+                Op::Bdv(x) => r[B] = shr(r[A], r[x]),
+                Op::Cdv(x) => r[C] = shr(r[A], r[x]),
+                Op::Reserved => return Err(Fault::ReservedOperand { pc }),
+            }
+            pc += 2;
+        }
+        Ok(())
+    }
+}
+
+/// `a / 2^shift`, which is zero once the shift covers every bit.
+fn shr(a: u64, shift: u64) -> u64 {
+    if shift < 64 {
+        a >> shift
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::Cpu;
+
+    /// Runs `program` both ways for each A and checks that they output the
+    /// same values and fault the same way.
+    fn agree(program: &[u8], a_values: impl IntoIterator<Item = u64>) {
+        let compiled = Compiled::new(program).unwrap();
+        let mut output = vec![];
+        for a in a_values {
+            let registers = Registers { a, b: 0, c: 0 };
+            let mut cpu = Cpu::new(program, registers).unwrap();
+            let expected = cpu.run().map(|_| ());
+            let result = compiled.run(registers, &mut output);
+            assert_eq!(result, expected, "{:?} with A={}", program, a);
+            assert_eq!(output, cpu.output, "{:?} with A={}", program, a);
+            if result.is_ok() {
+                assert!(compiled.outputs(registers, &cpu.output).unwrap());
+                assert_eq!(
+                    compiled.outputs(registers, program).unwrap(),
+                    cpu.output == program
+                );
+            }
+        }
+    }
+
+    fn a_values() -> impl Iterator<Item = u64> {
+        (0..2048).chain([117440, 66171486, 90938893795561, u64::MAX])
+    }
+
+    #[test]
+    fn matches_the_interpreter_on_the_samples() {
+        agree(&[0, 1, 5, 4, 3, 0], a_values());
+        agree(&[0, 3, 5, 4, 3, 0], a_values());
+    }
+
+    #[test]
+    fn matches_the_interpreter_on_the_input() {
+        agree(
+            &[2, 4, 1, 6, 7, 5, 4, 6, 1, 4, 5, 5, 0, 3, 3, 0],
+            a_values(),
+        );
+    }
+
+    #[test]
+    fn faults_only_on_reaching_a_reserved_operand() {
+        // With A set this jumps over the `adv` with combo operand 7, and with
+        // A clear it reaches it.
+        let program = [3, 4, 0, 7, 5, 4];
+        agree(&program, 0..16);
+        let compiled = Compiled::new(&program).unwrap();
+        let fault = compiled.run(Registers { a: 0, b: 0, c: 0 }, &mut vec![]);
+        assert_eq!(fault, Err(Fault::ReservedOperand { pc: 2 }));
+
+        // A jump to an odd position decodes the pairs the other way.
+        agree(&[3, 1, 5, 0, 3, 7], 0..16);
+    }
+}
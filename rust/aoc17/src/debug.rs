@@ -0,0 +1,321 @@
+//! A step debugger and an execution trace for the 3-bit computer.
+//!
+//! The debugger reads one command per line, so it works interactively or
+//! from a script piped into it:
+//!
+//! ```text
+//! step [N]      s    run one (or N) instructions
+//! continue      c    run to the next breakpoint, until halted or until the
+//!                    step limit
+//! break PC      b    toggle a breakpoint on an instruction address
+//! set R VALUE        set register A, B or C
+//! regs          r    show the registers and the next instruction
+//! list          l    disassemble, marking the pc and breakpoints
+//! out                show the output so far
+//! watch         w    toggle printing each value as it is output
+//! reset              start over from the initial registers
+//! quit          q
+//! ```
+
+use std::collections::BTreeSet;
+use std::io::{BufRead, Write};
+
+use aoc_common::Result;
+
+use crate::asm;
+use crate::vm::{Cpu, Halt, Registers, Word};
+
+/// How many instructions `continue` and [`trace`] run by default before
+/// giving up on a program that may never halt.
+pub const MAX_STEPS: usize = 1_000_000;
+
+pub struct Debugger<W = u64> {
+    pub cpu: Cpu<W>,
+    /// The most instructions a single `continue` runs.
+    pub max_steps: usize,
+    initial: Registers<W>,
+    breakpoints: BTreeSet<usize>,
+    watch: bool,
+}
+
+impl<W: Word> Debugger<W> {
+    pub fn new(cpu: Cpu<W>) -> Debugger<W> {
+        Debugger {
+            initial: cpu.registers.clone(),
+            cpu,
+            max_steps: MAX_STEPS,
+            breakpoints: BTreeSet::new(),
+            watch: false,
+        }
+    }
+
+    /// Runs every command in `input` until it ends or asks to quit. A bad
+    /// command is reported and the session goes on.
+    pub fn session(&mut self, input: impl BufRead, out: &mut impl Write) -> Result<()> {
+        for line in input.lines() {
+            match self.command(&line?, out) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => writeln!(out, "error: {}", e)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs one command, writing what it shows to `out`. Returns `false` once
+    /// the user asks to quit.
+    pub fn command(&mut self, line: &str, out: &mut impl Write) -> Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] => {}
+            ["s" | "step"] => self.step(1, out)?,
+            ["s" | "step", n] => self.step(n.parse()?, out)?,
+            ["c" | "continue"] => self.resume(out)?,
+            ["b" | "break", pc] => {
+                let pc = pc.parse()?;
+                if !self.breakpoints.remove(&pc) {
+                    self.breakpoints.insert(pc);
+                }
+                writeln!(out, "breakpoints: {:?}", self.breakpoints)?;
+            }
+            ["set", register, value] => {
+                let value: W = value
+                    .parse()
+                    .map_err(|_| format!("not a register value: {}", value))?;
+                match register.to_ascii_uppercase().as_str() {
+                    "A" => self.cpu.registers.a = value,
+                    "B" => self.cpu.registers.b = value,
+                    "C" => self.cpu.registers.c = value,
+                    _ => return Err(format!("no register {}", register).into()),
+                }
+                self.show(out)?;
+            }
+            ["r" | "regs"] => self.show(out)?,
+            ["l" | "list"] => self.list(out)?,
+            ["out"] => writeln!(out, "out: {}", asm::to_comma(&self.cpu.output))?,
+            ["w" | "watch"] => {
+                self.watch = !self.watch;
+                writeln!(out, "watch {}", if self.watch { "on" } else { "off" })?;
+            }
+            ["reset"] => {
+                self.cpu.reset(self.initial.clone());
+                self.show(out)?;
+            }
+            ["q" | "quit"] => return Ok(false),
+            _ => return Err(format!("unknown command `{}`", line.trim()).into()),
+        }
+        Ok(true)
+    }
+
+    fn step(&mut self, n: usize, out: &mut impl Write) -> Result<()> {
+        for _ in 0..n {
+            if self.execute(out)?.is_some() {
+                break;
+            }
+        }
+        self.show(out)
+    }
+
+    fn resume(&mut self, out: &mut impl Write) -> Result<()> {
+        // Always move at least one instruction, so continuing from a
+        // breakpoint does not stop on it again.
+        for steps in 1.. {
+            if self.execute(out)?.is_some() {
+                break;
+            }
+            if self.breakpoints.contains(&self.cpu.pc) {
+                writeln!(out, "breakpoint at {}", self.cpu.pc)?;
+                break;
+            }
+            if steps == self.max_steps {
+                writeln!(out, "stopped after {} steps", steps)?;
+                break;
+            }
+        }
+        self.show(out)
+    }
+
+    /// One instruction, reporting new output if watched and a halt if any.
+    fn execute(&mut self, out: &mut impl Write) -> Result<Option<Halt>> {
+        let before = self.cpu.output.len();
+        let halt = self.cpu.step()?;
+        if self.watch {
+            if let Some(value) = self.cpu.output.get(before) {
+                writeln!(out, "out {}", value)?;
+            }
+        }
+        if let Some(halt) = halt {
+            writeln!(out, "halted: {:?}", halt)?;
+        }
+        Ok(halt)
+    }
+
+    fn show(&self, out: &mut impl Write) -> Result<()> {
+        let r = &self.cpu.registers;
+        let next = match self.cpu.current()? {
+            Some(instruction) => instruction.to_string(),
+            None => "halted".to_string(),
+        };
+        writeln!(
+            out,
+            "pc={:<3} A={} B={} C={}  next: {}",
+            self.cpu.pc, r.a, r.b, r.c, next
+        )?;
+        Ok(())
+    }
+
+    fn list(&self, out: &mut impl Write) -> Result<()> {
+        for (i, pair) in self.cpu.program().chunks(2).enumerate() {
+            let pc = 2 * i;
+            let text = asm::pair_text(pair[0], pair[1]);
+            let marker = if pc == self.cpu.pc { "=>" } else { "  " };
+            let stop = if self.breakpoints.contains(&pc) {
+                "*"
+            } else {
+                " "
+            };
+            writeln!(out, "{}{}{:>3}: {}", marker, stop, pc, text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `cpu` for at most `max_steps` instructions, writing one JSON object
+/// per executed instruction: the step number, the address and text of the
+/// instruction, the registers after it, and the value it output, if any.
+/// Returns [`Halt::StepLimit`] if the program was still running.
+pub fn trace<W: Word>(cpu: &mut Cpu<W>, max_steps: usize, out: &mut impl Write) -> Result<Halt> {
+    for step in 0..max_steps {
+        let pc = cpu.pc;
+        let Some(instruction) = cpu.current()? else {
+            return Ok(Halt::EndOfProgram);
+        };
+        let before = cpu.output.len();
+        cpu.step()?;
+
+        let r = &cpu.registers;
+        let output = match cpu.output.get(before) {
+            Some(value) => value.to_string(),
+            None => "null".to_string(),
+        };
+        writeln!(
+            out,
+            r#"{{"step":{},"pc":{},"instruction":"{}","a":{},"b":{},"c":{},"out":{}}}"#,
+            step, pc, instruction, r.a, r.b, r.c, output
+        )?;
+    }
+    match cpu.current()? {
+        Some(_) => Ok(Halt::StepLimit),
+        None => Ok(Halt::EndOfProgram),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The first sample: it outputs A>>1 % 8 and shifts A right by 1 until
+    /// A is 0.
+    fn sample() -> Debugger {
+        let registers = Registers { a: 729, b: 0, c: 0 };
+        Debugger::new(Cpu::new(&[0, 1, 5, 4, 3, 0], registers).unwrap())
+    }
+
+    fn session(debugger: &mut Debugger, script: &[u8]) -> String {
+        let mut out = vec![];
+        debugger.session(script, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn steps_and_shows_the_registers() {
+        let out = session(&mut sample(), b"s\ns 2\nr\n");
+        assert_eq!(
+            out,
+            "\
+pc=2   A=364 B=0 C=0  next: out A%8
+pc=0   A=364 B=0 C=0  next: adv A>>1
+pc=0   A=364 B=0 C=0  next: adv A>>1
+"
+        );
+    }
+
+    #[test]
+    fn continues_to_breakpoints() {
+        let out = session(&mut sample(), b"b 4\nc\nc\nb 4\nc\nout\n");
+        assert_eq!(
+            out,
+            "\
+breakpoints: {4}
+breakpoint at 4
+pc=4   A=364 B=0 C=0  next: jnz 0
+breakpoint at 4
+pc=4   A=182 B=0 C=0  next: jnz 0
+breakpoints: {}
+halted: EndOfProgram
+pc=6   A=0 B=0 C=0  next: halted
+out: 4,6,3,5,6,3,5,2,1,0
+"
+        );
+    }
+
+    #[test]
+    fn watches_the_output() {
+        let out = session(&mut sample(), b"w\ns 3\nw\ns 3\n");
+        assert_eq!(
+            out,
+            "\
+watch on
+out 4
+pc=0   A=364 B=0 C=0  next: adv A>>1
+watch off
+pc=0   A=182 B=0 C=0  next: adv A>>1
+"
+        );
+    }
+
+    #[test]
+    fn reports_bad_commands_and_goes_on() {
+        let out = session(&mut sample(), b"jump\nset D 1\nset a 8\nq\nr\n");
+        assert_eq!(
+            out,
+            "\
+error: unknown command `jump`
+error: no register D
+pc=0   A=8 B=0 C=0  next: adv A>>1
+"
+        );
+    }
+
+    #[test]
+    fn continue_stops_a_program_that_never_halts() {
+        let registers = Registers { a: 1, b: 0, c: 0 };
+        let mut debugger = Debugger::new(Cpu::new(&[3, 0], registers).unwrap());
+        debugger.max_steps = 10;
+        let out = session(&mut debugger, b"c\n");
+        assert_eq!(
+            out,
+            "stopped after 10 steps\npc=0   A=1 B=0 C=0  next: jnz 0\n"
+        );
+    }
+
+    #[test]
+    fn traces_json_lines() {
+        let mut cpu = sample().cpu;
+        let mut out = vec![];
+        assert_eq!(trace(&mut cpu, 4, &mut out).unwrap(), Halt::StepLimit);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"{"step":0,"pc":0,"instruction":"adv A>>1","a":364,"b":0,"c":0,"out":null}
+{"step":1,"pc":2,"instruction":"out A%8","a":364,"b":0,"c":0,"out":4}
+{"step":2,"pc":4,"instruction":"jnz 0","a":364,"b":0,"c":0,"out":null}
+{"step":3,"pc":0,"instruction":"adv A>>1","a":182,"b":0,"c":0,"out":null}
+"#
+        );
+
+        let mut cpu = sample().cpu;
+        let halt = trace(&mut cpu, MAX_STEPS, &mut std::io::sink()).unwrap();
+        assert_eq!(halt, Halt::EndOfProgram);
+        assert_eq!(cpu.output, [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    }
+}
@@ -0,0 +1,138 @@
+use aoc_common::{parse, Answer, Cursor, Params, Result, Solution};
+use vm::{Cpu, Halt, Registers};
+
+pub mod asm;
+pub mod compile;
+pub mod debug;
+pub mod quine;
+pub mod vm;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Machine;
+
+    fn parse(&self, input: &str) -> Result<Machine> {
+        parse(input)
+    }
+
+    fn part1(&self, machine: &Machine, _params: &Params) -> Result<Answer> {
+        part1(machine)
+    }
+
+    fn part2(&self, machine: &Machine, _params: &Params) -> Result<Answer> {
+        part2(machine)
+    }
+}
+
+/// A program for the 3-bit computer, as its list of opcodes and operands.
+pub type Program = Vec<u8>;
+
+/// The computer's initial registers and the program it runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub registers: Registers,
+    pub program: Program,
+}
+
+/// Parses the puzzle input,
+///
+/// ```text
+/// Register A: 729
+/// Register B: 0
+/// Register C: 0
+///
+/// Program: 0,1,5,4,3,0
+/// ```
+///
+/// or just a comma-separated program, in which case the registers start at 0.
+pub fn parse(input: &str) -> Result<Machine> {
+    if !input.trim_start().starts_with("Register") {
+        let line = parse::lines(input).next().ok_or("the program is empty")?;
+        return Ok(Machine {
+            registers: Registers::default(),
+            program: parse_program(line)?,
+        });
+    }
+
+    let [block, program] = parse::sections(input)?;
+    let mut lines = block.lines();
+    let mut end = block.clone();
+    end.take_while(|_| true);
+    let mut register = |name: &str| -> Result<u64> {
+        let mut line = lines
+            .next()
+            .ok_or_else(|| end.error(format!("a line for register {}", name)))?;
+        line.expect(&format!("Register {}: ", name))?;
+        let value = line.number()?;
+        line.end()?;
+        Ok(value)
+    };
+    let registers = Registers {
+        a: register("A")?,
+        b: register("B")?,
+        c: register("C")?,
+    };
+    if let Some(extra) = lines.next() {
+        return Err(extra.error("a blank line before the program").into());
+    }
+
+    let mut line = program;
+    line.expect("Program: ")?;
+    Ok(Machine {
+        registers,
+        program: parse_program(line)?,
+    })
+}
+
+fn parse_program(mut line: Cursor) -> Result<Program> {
+    let program = line.separated(",", |l| {
+        let start = l.clone();
+        match l.number()? {
+            n @ 0..=7 => Ok(n),
+            _ => Err(start.error("a 3-bit number")),
+        }
+    })?;
+    line.end()?;
+    Ok(program)
+}
+
+/// Runs the program to its end. A program that loops forever is stopped
+/// after the debugger's step limit.
+fn part1(machine: &Machine) -> Result<Answer> {
+    let mut cpu = Cpu::new(&machine.program, machine.registers)?;
+    if cpu.run_for(debug::MAX_STEPS)? == Halt::StepLimit {
+        return Err(format!(
+            "the program was still running after {} steps",
+            debug::MAX_STEPS
+        )
+        .into());
+    }
+
+    let output: Vec<String> = cpu.output.iter().map(|x| x.to_string()).collect();
+    Ok(output.join(",").into())
+}
+
+fn part2(machine: &Machine) -> Result<Answer> {
+    let a = quine::solve_any(&machine.program)?.ok_or("no value of A reproduces the program")?;
+    Ok(match a.parse::<i128>() {
+        Ok(n) => n.into(),
+        Err(_) => a.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endless_programs_are_stopped() {
+        // jnz 0 jumps back to itself for as long as A is not 0.
+        let machine =
+            parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n").unwrap();
+        assert_eq!(
+            part1(&machine).unwrap_err().to_string(),
+            "the program was still running after 1000000 steps"
+        );
+    }
+}
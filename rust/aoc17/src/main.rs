@@ -1,179 +1,163 @@
-use std::collections::{HashMap, HashSet};
-use std::io::{self, Read};
-
-type Result<T> = ::std::result::Result<T, Box<dyn::std::error::Error>>;
-
+use std::env;
+use std::io;
+use std::time::{Duration, Instant};
+
+use aoc17::compile::Compiled;
+use aoc17::debug::{self, Debugger};
+use aoc17::vm::{Cpu, Halt, Registers};
+use aoc17::Machine;
+use aoc17::{asm, quine};
+use aoc_common::{read, Result};
+
+/// `aoc17 disasm [FILE]` lists a puzzle input's program as mnemonics and
+/// `aoc17 asm [FILE]` turns such a listing back into the comma form.
+/// `aoc17 debug [--max-steps N] FILE` steps through the program with commands
+/// read from stdin, and `aoc17 --trace [--max-steps N] [FILE]` prints every
+/// executed instruction as a JSON line; both give up on a program still
+/// running after N instructions. `aoc17 bench [FILE] [COUNT]` times the
+/// interpreter against the compiled program over many values of A.
+/// `aoc17 quine [FILE]` explains how the program's loop is searched for the A
+/// that makes it print itself. Anything else solves the puzzle.
 fn main() -> Result<()> {
-    // let mut input = String::new();
-    // io::stdin().read_to_string(&mut input)?;
-
-    // part1("0,1,5,4,3,0")?;
-    part1("2,4,1,6,7,5,4,6,1,4,5,5,0,3,3,0")?;
-    part2("2,4,1,6,7,5,4,6,1,4,5,5,0,3,3,0")?;
-
-    Ok(())
-}
-
-fn part1(input: &str) -> Result<()> {
-    let mut registers = HashMap::new();
-    // registers.insert("A", 729);
-    registers.insert("A", 66171486);
-    registers.insert("B", 0);
-    registers.insert("C", 0);
-
-    let program: Vec<usize> = input
-        .trim()
-        .split(",")
-        .map(|x| x.parse().unwrap())
-        .collect();
-
-    let out = execute(&program, &mut registers);
-    let output: String = out.iter().map(|x| x.to_string()).collect();
-    println!("{}", output);
-
-    Ok(())
-}
-
-fn part2(input: &str) -> Result<()> {
-    let mut registers = HashMap::new();
-    // registers.insert("A", 729);
-    registers.insert("A", 2024);
-    registers.insert("B", 0);
-    registers.insert("C", 0);
-
-    let program: Vec<usize> = input
-        .trim()
-        .split(",")
-        .map(|x| x.parse().unwrap())
-        .collect();
-
-    let mut saved = vec![];
-    for i in 1..1024 {
-        registers.insert("A", i);
-        registers.insert("B", 0);
-        registers.insert("C", 0);
-        let output = execute(&program, &mut registers);
-
-        if output[0] == program[0] {
-            saved.push(i);
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("disasm") => {
+            let machine = aoc17::parse(&read(args.get(1))?)?;
+            print!("{}", asm::disassemble(&machine.program)?);
+            Ok(())
         }
-    }
-
-    let mut pos = 1;
-    while pos < program.len() {
-        let mut next = vec![];
-        for v in saved {
-            for bit in 0..8 {
-                let num = (bit << (7 + 3 * pos)) | v;
-                registers.insert("A", num);
-                registers.insert("B", 0);
-                registers.insert("C", 0);
-
-                let output = execute(&program, &mut registers);
-
-                if output.len() > pos && output[pos] == program[pos] {
-                    next.push(num);
-                }
+        Some("asm") => {
+            let program = asm::assemble(&read(args.get(1))?)?;
+            println!("{}", asm::to_comma(&program));
+            Ok(())
+        }
+        Some("debug") => {
+            let (max_steps, args) = max_steps(&args[1..])?;
+            let file = args
+                .first()
+                .ok_or("usage: aoc17 debug [--max-steps N] FILE < COMMANDS")?;
+            let machine = aoc17::parse(&read(Some(file))?)?;
+            let mut debugger = Debugger::new(Cpu::new(&machine.program, machine.registers)?);
+            debugger.max_steps = max_steps;
+            debugger.session(io::stdin().lock(), &mut io::stdout().lock())
+        }
+        Some("quine") => {
+            let machine = aoc17::parse(&read(args.get(1))?)?;
+            let shape = quine::analyze(&machine.program)?;
+            println!(
+                "A shifts by {} per pass; each output depends on its low {} bits",
+                shape.shift, shape.window
+            );
+            match quine::solve_any(&machine.program)? {
+                Some(a) => println!("A = {}", a),
+                None => println!("no value of A reproduces the program"),
+            }
+            Ok(())
+        }
+        Some("bench") => {
+            let machine = aoc17::parse(&read(args.get(1))?)?;
+            let count = match args.get(2) {
+                Some(count) => count.parse()?,
+                None => 1_000_000,
+            };
+            bench(&machine, count)
+        }
+        Some("--trace") => {
+            let (max_steps, args) = max_steps(&args[1..])?;
+            let machine = aoc17::parse(&read(args.first())?)?;
+            let mut cpu = Cpu::new(&machine.program, machine.registers)?;
+            if debug::trace(&mut cpu, max_steps, &mut io::stdout().lock())? == Halt::StepLimit {
+                return Err(
+                    format!("the program was still running after {} steps", max_steps).into(),
+                );
             }
+            Ok(())
         }
-        saved = next;
-        pos += 1;
+        _ => aoc_common::run(&aoc17::Day17),
     }
-
-    let min = saved.iter().min().unwrap();
-    println!("{}", *min);
-
-    Ok(())
 }
 
-fn execute(program: &[usize], registers: &mut HashMap<&str, usize>) -> Vec<usize> {
-    let mut out = vec![];
+/// Takes a leading `--max-steps N` off the arguments, or the default limit.
+fn max_steps(args: &[String]) -> Result<(usize, &[String])> {
+    match args {
+        [flag, n, rest @ ..] if flag == "--max-steps" => Ok((n.parse()?, rest)),
+        [flag] if flag == "--max-steps" => Err("--max-steps needs a count".into()),
+        _ => Ok((debug::MAX_STEPS, args)),
+    }
+}
 
-    let mut pc = 0;
-    loop {
-        if pc >= program.len() {
-            break;
+/// Times the interpreter against the compiled program over `count` values
+/// of A, counting up from the input's: once running each to the end, and once
+/// checking each as a quine candidate the way a brute-force search would.
+fn bench(machine: &Machine, count: u64) -> Result<()> {
+    let program = &machine.program;
+    let registers = |i: u64| Registers {
+        a: machine.registers.a + i,
+        ..machine.registers
+    };
+    let mut cpu = Cpu::new(program, machine.registers)?;
+    let compiled = Compiled::new(program)?;
+    let mut output = vec![];
+
+    let run = timed(|| {
+        for i in 0..count {
+            cpu.reset(registers(i));
+            cpu.run()?;
         }
-
-        let instr = program[pc];
-        let mut operand = program[pc + 1];
-        match instr {
-            0 => {
-                // adv
-                let numerator = registers.get("A").unwrap();
-                operand = operand_to_value(operand, &registers);
-                let denominator = 2_usize.pow(operand as u32);
-                let quotient = numerator / denominator;
-                registers.insert("A", quotient);
-            }
-            1 => {
-                // bxl
-                let value = registers.get("B").unwrap();
-                let bitwise = value ^ operand;
-                registers.insert("B", bitwise);
-            }
-            2 => {
-                // bst
-                operand = operand_to_value(operand, &registers);
-                let value = operand % 8;
-                registers.insert("B", value);
-            }
-            3 => {
-                // jnz
-                let value = registers.get("A").unwrap();
-                if *value != 0 {
-                    pc = operand as usize;
-                    continue;
-                }
-            }
-            4 => {
-                // bxc
-                let left = registers.get("B").unwrap();
-                let right = registers.get("C").unwrap();
-                let bitwise = left ^ right;
-                registers.insert("B", bitwise);
-            }
-            5 => {
-                // out
-                operand = operand_to_value(operand, &registers);
-                let value = operand % 8;
-                out.push(value);
-            }
-            6 => {
-                // bdv
-                operand = operand_to_value(operand, &registers);
-                let numerator = registers.get("A").unwrap();
-                let denominator = 2_usize.pow(operand as u32);
-                let quotient = numerator / denominator;
-                registers.insert("B", quotient);
-            }
-            7 => {
-                // cdv
-                operand = operand_to_value(operand, &registers);
-                let numerator = registers.get("A").unwrap();
-                let denominator = 2_usize.pow(operand as u32);
-                let quotient = numerator / denominator;
-                registers.insert("C", quotient);
-            }
-            _ => {
-                println!("Unknown instruction: {}", instr);
-                break;
-            }
+        Ok(())
+    })?;
+    let run_compiled = timed(|| {
+        for i in 0..count {
+            compiled.run(registers(i), &mut output)?;
         }
+        Ok(())
+    })?;
+    // The last run of each should agree.
+    if output != cpu.output {
+        return Err(format!("compiled output {:?}, not {:?}", output, cpu.output).into());
+    }
 
-        pc += 2;
+    let (mut hits, mut hits_compiled) = (0, 0);
+    let search = timed(|| {
+        for i in 0..count {
+            cpu.reset(registers(i));
+            cpu.run()?;
+            hits += usize::from(cpu.output == *program);
+        }
+        Ok(())
+    })?;
+    let search_compiled = timed(|| {
+        for i in 0..count {
+            hits_compiled += usize::from(compiled.outputs(registers(i), program)?);
+        }
+        Ok(())
+    })?;
+    if hits != hits_compiled {
+        return Err(format!("compiled found {} quines, not {}", hits_compiled, hits).into());
     }
 
-    out
+    let rate = |elapsed: Duration| count as f64 / elapsed.as_secs_f64();
+    println!(
+        "{:<8} {:>16} {:>16} {:>8}",
+        "", "Interpreter/s", "Compiled/s", "Speedup"
+    );
+    for (name, slow, fast) in [
+        ("run", run, run_compiled),
+        ("search", search, search_compiled),
+    ] {
+        println!(
+            "{:<8} {:>16.0} {:>16.0} {:>7.1}x",
+            name,
+            rate(slow),
+            rate(fast),
+            slow.as_secs_f64() / fast.as_secs_f64()
+        );
+    }
+    Ok(())
 }
 
-fn operand_to_value(operand: usize, registers: &HashMap<&str, usize>) -> usize {
-    match operand {
-        0..=3 => operand,
-        4 => *registers.get("A").unwrap(),
-        5 => *registers.get("B").unwrap(),
-        6 => *registers.get("C").unwrap(),
-        7 => operand,
-        _ => panic!("Unknown operand: {}", operand),
-    }
+fn timed(f: impl FnOnce() -> Result<()>) -> Result<Duration> {
+    let start = Instant::now();
+    f()?;
+    Ok(start.elapsed())
 }
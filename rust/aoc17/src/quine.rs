@@ -0,0 +1,369 @@
+//! Finding the smallest A for which a program outputs itself.
+//!
+//! This works for the family of programs the puzzle inputs belong to: a
+//! single loop that ends in `jnz 0`, shifts A right by a constant once per
+//! pass and outputs one value per pass, with B and C recomputed from A each
+//! time. Each output then depends only on the value of A at the start of its
+//! pass, and that value is the next pass's A with a few more low bits. The
+//! search fixes those bits from the last output back to the first.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use aoc_common::Result;
+
+use num_bigint::BigUint;
+
+use crate::vm::{Combo, Cpu, Fault, Instruction, Literal, Register, Registers, Word};
+
+/// The loop structure the search relies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Loop {
+    /// How far `adv` shifts A on each pass.
+    pub shift: u32,
+    /// How many low bits of A, as it is at the start of a pass, the pass's
+    /// output can depend on.
+    pub window: u32,
+}
+
+/// Why a program is outside the family [`analyze`] understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unsupported {
+    /// The program is not a loop closed by a final `jnz 0`.
+    NoFinalJump,
+    /// There is a jump in the body of the loop, at `pc`.
+    InnerJump { pc: usize },
+    /// The body does not shift A exactly once.
+    AdvCount { count: usize },
+    /// The `adv` at `pc` shifts by a register or by nothing.
+    AdvNotConstant { pc: usize },
+    /// The body does not output exactly once.
+    OutCount { count: usize },
+    /// The instruction at `pc` reads a register before the pass writes it,
+    /// so a pass depends on the one before.
+    CarriedRegister { pc: usize, register: Register },
+    /// The instruction at `pc` shifts A by a value that is not below 8.
+    WideShift { pc: usize },
+}
+
+impl Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsupported program shape: ")?;
+        match self {
+            Unsupported::NoFinalJump => write!(f, "the last instruction should be jnz 0"),
+            Unsupported::InnerJump { pc } => {
+                write!(f, "the jump at {} is not the one closing the loop", pc)
+            }
+            Unsupported::AdvCount { count } => {
+                write!(
+                    f,
+                    "the loop should run adv once, but runs it {} times",
+                    count
+                )
+            }
+            Unsupported::AdvNotConstant { pc } => {
+                write!(f, "the adv at {} should shift by a constant 1-3", pc)
+            }
+            Unsupported::OutCount { count } => {
+                write!(
+                    f,
+                    "the loop should output once, but outputs {} times",
+                    count
+                )
+            }
+            Unsupported::CarriedRegister { pc, register } => write!(
+                f,
+                "the instruction at {} reads {} before the loop sets it",
+                pc, register
+            ),
+            Unsupported::WideShift { pc } => {
+                write!(f, "the instruction at {} shifts A by more than 7", pc)
+            }
+        }
+    }
+}
+
+impl Error for Unsupported {}
+
+/// What the analysis knows about a register partway through a pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Value {
+    /// Left over from the previous pass.
+    Carried,
+    /// Below 8.
+    Small,
+    /// Derived from A. Only its low 3 bits reach the output.
+    Wide,
+}
+
+/// Checks that `program` has the supported shape and measures its loop.
+pub fn analyze(program: &[u8]) -> Result<Loop> {
+    if !program.len().is_multiple_of(2) {
+        return Err(Fault::OddLength { len: program.len() }.into());
+    }
+    let instructions = program
+        .chunks(2)
+        .enumerate()
+        .map(|(i, pair)| {
+            Instruction::decode(pair[0], pair[1]).ok_or(Fault::ReservedOperand { pc: 2 * i })
+        })
+        .collect::<std::result::Result<Vec<_>, Fault>>()?;
+
+    let Some((Instruction::Jnz(Literal(0)), body)) = instructions.split_last() else {
+        return Err(Unsupported::NoFinalJump.into());
+    };
+
+    let mut shift = None;
+    let mut advs = 0;
+    let mut outs = 0;
+    let mut window = 0;
+    let (mut b, mut c) = (Value::Carried, Value::Carried);
+
+    for (i, &instruction) in body.iter().enumerate() {
+        let pc = 2 * i;
+        // A has already been shifted if this comes after the adv.
+        let offset = shift.unwrap_or(0);
+        let value = |x: Combo, b: Value, c: Value| -> Result<Value> {
+            let value = match x {
+                Combo::Constant(_) => Value::Small,
+                Combo::Register(Register::A) => Value::Wide,
+                Combo::Register(Register::B) => b,
+                Combo::Register(Register::C) => c,
+            };
+            if value == Value::Carried {
+                let Combo::Register(register) = x else {
+                    unreachable!("constants are never carried")
+                };
+                return Err(Unsupported::CarriedRegister { pc, register }.into());
+            }
+            Ok(value)
+        };
+        // The largest shift `x` can make, and the bits of A it brings down.
+        let mut shifted = |x: Combo, b: Value, c: Value| -> Result<()> {
+            let max = match (x, value(x, b, c)?) {
+                (Combo::Constant(n), _) => u32::from(n),
+                (_, Value::Small) => 7,
+                _ => return Err(Unsupported::WideShift { pc }.into()),
+            };
+            window = window.max(offset + max + 3);
+            Ok(())
+        };
+
+        match instruction {
+            Instruction::Adv(x) => {
+                advs += 1;
+                match x {
+                    Combo::Constant(n @ 1..=3) => shift = Some(u32::from(n)),
+                    _ => return Err(Unsupported::AdvNotConstant { pc }.into()),
+                }
+            }
+            Instruction::Bxl(_) => b = value(Combo::Register(Register::B), b, c)?,
+            Instruction::Bst(x) => {
+                if x == Combo::Register(Register::A) {
+                    window = window.max(offset + 3);
+                } else {
+                    value(x, b, c)?;
+                }
+                b = Value::Small;
+            }
+            Instruction::Jnz(_) => return Err(Unsupported::InnerJump { pc }.into()),
+            Instruction::Bxc(_) => {
+                let (x, y) = (
+                    value(Combo::Register(Register::B), b, c)?,
+                    value(Combo::Register(Register::C), b, c)?,
+                );
+                b = if x == Value::Wide || y == Value::Wide {
+                    Value::Wide
+                } else {
+                    Value::Small
+                };
+            }
+            Instruction::Out(x) => {
+                outs += 1;
+                if x == Combo::Register(Register::A) {
+                    window = window.max(offset + 3);
+                } else {
+                    value(x, b, c)?;
+                }
+            }
+            Instruction::Bdv(x) => {
+                shifted(x, b, c)?;
+                b = Value::Wide;
+            }
+            Instruction::Cdv(x) => {
+                shifted(x, b, c)?;
+                c = Value::Wide;
+            }
+        }
+    }
+
+    if advs != 1 {
+        return Err(Unsupported::AdvCount { count: advs }.into());
+    }
+    if outs != 1 {
+        return Err(Unsupported::OutCount { count: outs }.into());
+    }
+    Ok(Loop {
+        shift: shift.expect("one adv was seen"),
+        window,
+    })
+}
+
+/// The smallest positive A for which `program` outputs itself, or `None` if
+/// there is none. Fails if A does not fit in `W`.
+pub fn solve<W: Word>(program: &[u8]) -> Result<Option<W>> {
+    let shape = analyze(program)?;
+    let mut search = Search {
+        cpu: Cpu::new(program, Registers::default())?,
+        program,
+        shift: shape.shift,
+    };
+    search.from(program.len() - 1, W::default())
+}
+
+/// [`solve`] in the narrowest register type that can hold the answer, which
+/// is given in decimal.
+pub fn solve_any(program: &[u8]) -> Result<Option<String>> {
+    let shape = analyze(program)?;
+    // Each value of the program takes one more pass, and each pass adds
+    // `shift` low bits to A.
+    let bits = program.len() as u32 * shape.shift;
+    let a = if bits <= u64::BITS {
+        solve::<u64>(program)?.map(|a| a.to_string())
+    } else if bits <= u128::BITS {
+        solve::<u128>(program)?.map(|a| a.to_string())
+    } else {
+        solve::<BigUint>(program)?.map(|a| a.to_string())
+    };
+    Ok(a)
+}
+
+struct Search<'a, W> {
+    cpu: Cpu<W>,
+    program: &'a [u8],
+    shift: u32,
+}
+
+impl<W: Word> Search<'_, W> {
+    /// The first output of a pass that starts with `a`.
+    fn output(&mut self, a: W) -> Result<u8> {
+        self.cpu.reset(Registers {
+            a,
+            ..Registers::default()
+        });
+        while self.cpu.output.is_empty() {
+            self.cpu.step()?;
+        }
+        Ok(self.cpu.output[0])
+    }
+
+    /// The smallest A for the pass that outputs `program[index]`, given the
+    /// A the following pass starts with. Trying the new low bits in
+    /// increasing order, depth first, finds the smallest A overall, since the
+    /// bits for later passes are the high ones.
+    fn from(&mut self, index: usize, next: W) -> Result<Option<W>> {
+        for low in 0..1 << self.shift {
+            let a = next.shl_or(self.shift, low).ok_or_else(|| {
+                format!(
+                    "A for this program of {} values does not fit in the register type",
+                    self.program.len()
+                )
+            })?;
+            // A zero A would have ended the loop one pass earlier.
+            if a.is_zero() || self.output(a.clone())? != self.program[index] {
+                continue;
+            }
+            if index == 0 {
+                return Ok(Some(a));
+            }
+            if let Some(a) = self.from(index - 1, a)? {
+                return Ok(Some(a));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8] = &[2, 4, 1, 6, 7, 5, 4, 6, 1, 4, 5, 5, 0, 3, 3, 0];
+    const SAMPLE: &[u8] = &[0, 3, 5, 4, 3, 0];
+
+    #[test]
+    fn every_word_finds_the_same_a() {
+        for (program, a) in [(INPUT, 90938893795561), (SAMPLE, 117440)] {
+            assert_eq!(solve::<u64>(program).unwrap(), Some(a));
+            assert_eq!(solve::<u128>(program).unwrap(), Some(a.into()));
+            assert_eq!(solve::<BigUint>(program).unwrap(), Some(a.into()));
+            assert_eq!(solve_any(program).unwrap(), Some(a.to_string()));
+        }
+    }
+
+    fn unsupported(program: &[u8]) -> String {
+        analyze(program).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn explains_unsupported_shapes() {
+        let shape = "unsupported program shape: ";
+        for (program, message) in [
+            (&[0, 3, 5, 4][..], "the last instruction should be jnz 0"),
+            (&[0, 3, 5, 4, 3, 2], "the last instruction should be jnz 0"),
+            (
+                &[0, 3, 3, 0, 5, 4, 3, 0],
+                "the jump at 2 is not the one closing the loop",
+            ),
+            (
+                &[5, 4, 3, 0],
+                "the loop should run adv once, but runs it 0 times",
+            ),
+            (
+                &[0, 1, 0, 1, 5, 4, 3, 0],
+                "the loop should run adv once, but runs it 2 times",
+            ),
+            (
+                &[0, 4, 5, 4, 3, 0],
+                "the adv at 0 should shift by a constant 1-3",
+            ),
+            (
+                &[0, 3, 3, 0],
+                "the loop should output once, but outputs 0 times",
+            ),
+            (
+                &[0, 3, 5, 4, 5, 4, 3, 0],
+                "the loop should output once, but outputs 2 times",
+            ),
+            // The output comes from B, which only the previous pass set.
+            (
+                &[5, 5, 0, 3, 3, 0],
+                "the instruction at 0 reads B before the loop sets it",
+            ),
+            (
+                &[6, 4, 0, 3, 5, 5, 3, 0],
+                "the instruction at 0 shifts A by more than 7",
+            ),
+        ] {
+            assert_eq!(unsupported(program), format!("{}{}", shape, message));
+        }
+    }
+
+    #[test]
+    fn measures_supported_loops() {
+        assert_eq!(
+            analyze(INPUT).unwrap(),
+            Loop {
+                shift: 3,
+                window: 10
+            }
+        );
+        assert_eq!(
+            analyze(SAMPLE).unwrap(),
+            Loop {
+                shift: 3,
+                window: 6
+            }
+        );
+    }
+}
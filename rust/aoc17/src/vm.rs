@@ -0,0 +1,435 @@
+//! The 3-bit computer: three registers, eight instructions and an output
+//! stream. A [`Cpu`] decodes each instruction as it reaches it, because a
+//! jump may land on any program position.
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+
+/// A register value. The puzzle's registers are unbounded: `u64` holds A for
+/// any real input, and `u128` or [`BigUint`] let longer programs run.
+///
+/// Shifts are floor divisions by a power of two, so shifting by at least the
+/// width of the value gives 0 rather than overflowing.
+pub trait Word: Clone + Default + Debug + Display + FromStr + Eq + Ord + Hash {
+    fn from_u8(n: u8) -> Self;
+
+    fn is_zero(&self) -> bool;
+
+    /// `self % 8`
+    fn low_bits(&self) -> u8;
+
+    fn xor(&self, other: &Self) -> Self;
+
+    /// `self / 2^shift`
+    fn shr(&self, shift: &Self) -> Self;
+
+    /// `self << shift | low`, or `None` if that would lose high bits.
+    fn shl_or(&self, shift: u32, low: u8) -> Option<Self>;
+}
+
+macro_rules! primitive_word {
+    ($($t:ty),*) => {
+        $(
+            impl Word for $t {
+                fn from_u8(n: u8) -> Self {
+                    n.into()
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+
+                fn low_bits(&self) -> u8 {
+                    (*self % 8) as u8
+                }
+
+                fn xor(&self, other: &Self) -> Self {
+                    self ^ other
+                }
+
+                fn shr(&self, shift: &Self) -> Self {
+                    u32::try_from(*shift)
+                        .ok()
+                        .and_then(|s| self.checked_shr(s))
+                        .unwrap_or(0)
+                }
+
+                fn shl_or(&self, shift: u32, low: u8) -> Option<Self> {
+                    (self.leading_zeros() >= shift).then(|| self << shift | Self::from(low))
+                }
+            }
+        )*
+    };
+}
+
+primitive_word!(u64, u128);
+
+impl Word for BigUint {
+    fn from_u8(n: u8) -> Self {
+        n.into()
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(self)
+    }
+
+    fn low_bits(&self) -> u8 {
+        self.iter_u32_digits().next().map_or(0, |d| (d % 8) as u8)
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    fn shr(&self, shift: &Self) -> Self {
+        match shift.to_u64() {
+            Some(s) if s < self.bits() => self >> s,
+            _ => BigUint::zero(),
+        }
+    }
+
+    fn shl_or(&self, shift: u32, low: u8) -> Option<Self> {
+        Some(self << shift | BigUint::from(low))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Registers<W = u64> {
+    pub a: W,
+    pub b: W,
+    pub c: W,
+}
+
+impl<W: Word> Registers<W> {
+    pub fn get(&self, register: Register) -> &W {
+        match register {
+            Register::A => &self.a,
+            Register::B => &self.b,
+            Register::C => &self.c,
+        }
+    }
+
+    pub fn set(&mut self, register: Register, value: W) {
+        match register {
+            Register::A => self.a = value,
+            Register::B => self.b = value,
+            Register::C => self.c = value,
+        }
+    }
+}
+
+/// An operand used as its own value, 0 through 7.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Literal(pub u8);
+
+/// An operand that is either a small constant (0-3) or a register (4-6).
+/// 7 is reserved and never a valid combo operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Combo {
+    Constant(u8),
+    Register(Register),
+}
+
+impl Combo {
+    pub fn decode(operand: u8) -> Option<Combo> {
+        match operand {
+            0..=3 => Some(Combo::Constant(operand)),
+            4 => Some(Combo::Register(Register::A)),
+            5 => Some(Combo::Register(Register::B)),
+            6 => Some(Combo::Register(Register::C)),
+            _ => None,
+        }
+    }
+
+    pub fn encode(self) -> u8 {
+        match self {
+            Combo::Constant(n) => n,
+            Combo::Register(Register::A) => 4,
+            Combo::Register(Register::B) => 5,
+            Combo::Register(Register::C) => 6,
+        }
+    }
+
+    pub fn value<W: Word>(self, registers: &Registers<W>) -> W {
+        match self {
+            Combo::Constant(n) => W::from_u8(n),
+            Combo::Register(r) => registers.get(r).clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// `A = A >> combo`
+    Adv(Combo),
+    /// `B = B ^ literal`
+    Bxl(Literal),
+    /// `B = combo % 8`
+    Bst(Combo),
+    /// Jump to `literal` unless `A` is zero.
+    Jnz(Literal),
+    /// `B = B ^ C`. The operand is read but ignored.
+    Bxc(Literal),
+    /// Output `combo % 8`.
+    Out(Combo),
+    /// `B = A >> combo`
+    Bdv(Combo),
+    /// `C = A >> combo`
+    Cdv(Combo),
+}
+
+impl Instruction {
+    /// Decodes an opcode and its operand, both already known to be 3-bit.
+    pub fn decode(opcode: u8, operand: u8) -> Option<Instruction> {
+        let combo = Combo::decode(operand);
+        let literal = Literal(operand);
+        Some(match opcode {
+            0 => Instruction::Adv(combo?),
+            1 => Instruction::Bxl(literal),
+            2 => Instruction::Bst(combo?),
+            3 => Instruction::Jnz(literal),
+            4 => Instruction::Bxc(literal),
+            5 => Instruction::Out(combo?),
+            6 => Instruction::Bdv(combo?),
+            7 => Instruction::Cdv(combo?),
+            _ => return None,
+        })
+    }
+
+    /// The opcode and operand that [`Instruction::decode`] reads back as `self`.
+    pub fn encode(self) -> [u8; 2] {
+        match self {
+            Instruction::Adv(x) => [0, x.encode()],
+            Instruction::Bxl(Literal(n)) => [1, n],
+            Instruction::Bst(x) => [2, x.encode()],
+            Instruction::Jnz(Literal(n)) => [3, n],
+            Instruction::Bxc(Literal(n)) => [4, n],
+            Instruction::Out(x) => [5, x.encode()],
+            Instruction::Bdv(x) => [6, x.encode()],
+            Instruction::Cdv(x) => [7, x.encode()],
+        }
+    }
+}
+
+/// Why a program could not be loaded or could not go on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The program ends with an opcode that has no operand.
+    OddLength { len: usize },
+    /// A program value does not fit in 3 bits.
+    NotThreeBit { index: usize, value: u8 },
+    /// The instruction at `pc` uses the reserved combo operand 7.
+    ReservedOperand { pc: usize },
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fault::OddLength { len } => write!(
+                f,
+                "the program has {} values, but instructions come in pairs",
+                len
+            ),
+            Fault::NotThreeBit { index, value } => {
+                write!(f, "program value {} at {} is not 3-bit", value, index)
+            }
+            Fault::ReservedOperand { pc } => {
+                write!(f, "the instruction at {} uses combo operand 7", pc)
+            }
+        }
+    }
+}
+
+impl Error for Fault {}
+
+/// Why a run stopped without a fault.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Halt {
+    /// The instruction pointer moved past the last instruction.
+    EndOfProgram,
+    /// The run used up its step budget first.
+    StepLimit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cpu<W = u64> {
+    pub registers: Registers<W>,
+    pub pc: usize,
+    pub output: Vec<u8>,
+    program: Vec<u8>,
+}
+
+impl<W: Word> Cpu<W> {
+    pub fn new(program: &[u8], registers: Registers<W>) -> Result<Cpu<W>, Fault> {
+        check(program)?;
+        Ok(Cpu {
+            registers,
+            pc: 0,
+            output: vec![],
+            program: program.to_vec(),
+        })
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    /// Starts the program over with new registers and no output.
+    pub fn reset(&mut self, registers: Registers<W>) {
+        self.registers = registers;
+        self.pc = 0;
+        self.output.clear();
+    }
+
+    /// The instruction at the instruction pointer, or `None` once halted.
+    pub fn current(&self) -> Result<Option<Instruction>, Fault> {
+        match self.program.get(self.pc..self.pc + 2) {
+            Some(&[opcode, operand]) => Instruction::decode(opcode, operand)
+                .map(Some)
+                .ok_or(Fault::ReservedOperand { pc: self.pc }),
+            _ => Ok(None),
+        }
+    }
+
+    /// Executes one instruction. Returns `Some` if the program had already
+    /// halted, in which case nothing changes.
+    pub fn step(&mut self) -> Result<Option<Halt>, Fault> {
+        let Some(instruction) = self.current()? else {
+            return Ok(Some(Halt::EndOfProgram));
+        };
+
+        let r = &mut self.registers;
+        match instruction {
+            Instruction::Adv(x) => r.a = r.a.shr(&x.value(r)),
+            Instruction::Bxl(Literal(x)) => r.b = r.b.xor(&W::from_u8(x)),
+            Instruction::Bst(x) => r.b = W::from_u8(x.value(r).low_bits()),
+            Instruction::Jnz(Literal(target)) => {
+                if !r.a.is_zero() {
+                    self.pc = usize::from(target);
+                    return Ok(None);
+                }
+            }
+            Instruction::Bxc(_) => r.b = r.b.xor(&r.c),
+            Instruction::Out(x) => self.output.push(x.value(r).low_bits()),
+            Instruction::Bdv(x) => r.b = r.a.shr(&x.value(r)),
+            Instruction::Cdv(x) => r.c = r.a.shr(&x.value(r)),
+        }
+
+        self.pc += 2;
+        Ok(None)
+    }
+
+    /// Runs until the program halts.
+    pub fn run(&mut self) -> Result<Halt, Fault> {
+        loop {
+            if let Some(halt) = self.step()? {
+                return Ok(halt);
+            }
+        }
+    }
+
+    /// Runs for at most `steps` instructions.
+    pub fn run_for(&mut self, steps: usize) -> Result<Halt, Fault> {
+        for _ in 0..steps {
+            if let Some(halt) = self.step()? {
+                return Ok(halt);
+            }
+        }
+        match self.current()? {
+            None => Ok(Halt::EndOfProgram),
+            Some(_) => Ok(Halt::StepLimit),
+        }
+    }
+}
+
+/// Checks that `program` holds 3-bit values in pairs.
+pub(crate) fn check(program: &[u8]) -> Result<(), Fault> {
+    if let Some((index, &value)) = program.iter().enumerate().find(|(_, v)| **v > 7) {
+        return Err(Fault::NotThreeBit { index, value });
+    }
+    if !program.len().is_multiple_of(2) {
+        return Err(Fault::OddLength { len: program.len() });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn huge_shifts_give_zero() {
+        assert_eq!(u64::MAX.shr(&63), 1);
+        assert_eq!(u64::MAX.shr(&64), 0);
+        assert_eq!(u64::MAX.shr(&u64::MAX), 0);
+
+        assert_eq!(u128::MAX.shr(&127), 1);
+        assert_eq!(u128::MAX.shr(&128), 0);
+        assert_eq!(u128::MAX.shr(&(u128::from(u32::MAX) + 1)), 0);
+
+        let big = BigUint::from(1_u8) << 200_u32;
+        assert_eq!(big.shr(&BigUint::from(200_u8)), BigUint::from(1_u8));
+        assert_eq!(big.shr(&BigUint::from(201_u8)), BigUint::zero());
+        assert_eq!(big.shr(&(BigUint::from(1_u8) << 70_u32)), BigUint::zero());
+    }
+
+    /// The output of `program` started with A = `a`, in register type `W`.
+    fn output<W: Word>(program: &[u8], a: u64) -> Vec<u8> {
+        let a = a.to_string().parse().ok().unwrap();
+        let registers = Registers {
+            a,
+            ..Registers::default()
+        };
+        let mut cpu = Cpu::<W>::new(program, registers).unwrap();
+        cpu.run().unwrap();
+        cpu.output
+    }
+
+    #[test]
+    fn every_word_runs_the_same() {
+        let input = [2, 4, 1, 6, 7, 5, 4, 6, 1, 4, 5, 5, 0, 3, 3, 0];
+        for a in [0, 729, 66171486, 90938893795561, u64::MAX] {
+            let expected = output::<u64>(&input, a);
+            assert_eq!(output::<u128>(&input, a), expected);
+            assert_eq!(output::<BigUint>(&input, a), expected);
+        }
+    }
+
+    #[test]
+    fn odd_length_programs_do_not_load() {
+        let fault = Cpu::<u64>::new(&[0, 3, 5], Registers::default()).unwrap_err();
+        assert_eq!(fault, Fault::OddLength { len: 3 });
+        assert_eq!(
+            fault.to_string(),
+            "the program has 3 values, but instructions come in pairs"
+        );
+    }
+
+    #[test]
+    fn combo_operand_7_faults_when_reached() {
+        let mut cpu = Cpu::<u64>::new(&[0, 7], Registers::default()).unwrap();
+        let fault = cpu.run().unwrap_err();
+        assert_eq!(fault, Fault::ReservedOperand { pc: 0 });
+        assert_eq!(
+            fault.to_string(),
+            "the instruction at 0 uses combo operand 7"
+        );
+
+        // Skipped over, it is only data.
+        let registers = Registers { a: 1, b: 0, c: 0 };
+        let mut cpu = Cpu::<u64>::new(&[3, 4, 0, 7], registers).unwrap();
+        assert_eq!(cpu.run(), Ok(Halt::EndOfProgram));
+    }
+}
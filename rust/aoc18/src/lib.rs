@@ -0,0 +1,216 @@
+use aoc_common::{parse, Answer, Grid, Param, Params, Point, Result, Solution};
+use std::collections::{HashSet, VecDeque};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Point>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "size",
+            default: 70,
+            min: 0,
+            max: i64::MAX,
+            help: "Largest X and Y coordinate of the memory space",
+        },
+        Param {
+            name: "fallen",
+            default: 1024,
+            min: 0,
+            max: i64::MAX,
+            help: "Bytes that have fallen before part 1",
+        },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Vec<Point>> {
+        parse(input)
+    }
+
+    fn part1(&self, bytes: &Vec<Point>, params: &Params) -> Result<Answer> {
+        part1(bytes, params.get("size")?, params.get("fallen")?)
+    }
+
+    fn part2(&self, bytes: &Vec<Point>, params: &Params) -> Result<Answer> {
+        part2(bytes, params.get("size")?)
+    }
+}
+
+/// The falling bytes in order. Each line is `X,Y`, so `X` becomes the
+/// column and `Y` the row.
+pub fn parse(input: &str) -> Result<Vec<Point>> {
+    parse::lines(input)
+        .map(|mut line| {
+            let x = line.number()?;
+            line.expect(",")?;
+            let y = line.number()?;
+            line.end()?;
+            Ok(Point::new(y, x))
+        })
+        .collect()
+}
+
+fn part1(bytes: &[Point], size: usize, fallen: usize) -> Result<Answer> {
+    let grid = corrupted(&bytes[..bytes.len().min(fallen)], size, size)?;
+
+    // print!("{}", grid.map(|b| if *b { '#' } else { '.' }));
+
+    let exit = Point::new(size as i64, size as i64);
+    let score = dfs(&grid, Point::new(0, 0), exit);
+
+    Ok(score.into())
+}
+
+fn part2(bytes: &[Point], size: usize) -> Result<Answer> {
+    // Every byte has to fit, even those the search never gets to.
+    corrupted(bytes, size, size)?;
+    let exit = Point::new(size as i64, size as i64);
+    let mut low = 0;
+    let mut high = bytes.len();
+    let mut blocking_index = None;
+
+    while low < high {
+        let mid = (low + high) / 2;
+
+        // Construct the grid with the first `mid` bytes
+        let grid = corrupted(&bytes[..mid], size, size)?;
+
+        let score = bfs(&grid, Point::new(0, 0), exit);
+
+        if score == -1 {
+            // Path is blocked at `mid`, try to see if there's an earlier block
+            blocking_index = Some(mid);
+            high = mid;
+        } else {
+            // Path is still open, try more bytes
+            low = mid + 1;
+        }
+    }
+
+    // At this point, `blocking_index` holds the earliest byte index that blocks the path.
+    // Report the coordinates of that byte.
+    let idx = blocking_index.ok_or("no byte blocks the path")?;
+    let byte = bytes[idx - 1];
+    Ok(format!("{},{}", byte.col, byte.row).into())
+}
+
+/// Memory space spanning `0..=width` by `0..=height`, `true` where a byte has fallen.
+/// Fails if a byte falls outside it, e.g. when `size` is too small for the input.
+fn corrupted(bytes: &[Point], width: usize, height: usize) -> Result<Grid<bool>> {
+    let mut grid = Grid::new(height + 1, width + 1, false);
+    for b in bytes {
+        if !grid.in_bounds(*b) {
+            return Err(format!(
+                "byte {},{} is outside the {}x{} memory space",
+                b.col,
+                b.row,
+                width + 1,
+                height + 1
+            )
+            .into());
+        }
+        grid[*b] = true;
+    }
+    Ok(grid)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct State {
+    pos: Point,
+    score: i32,
+}
+
+fn dfs(grid: &Grid<bool>, start: Point, end: Point) -> i32 {
+    let mut visited = HashSet::new();
+    let state = State {
+        pos: start,
+        score: 0,
+    };
+
+    let mut stack = vec![state];
+
+    while let Some(state) = min_score(&mut stack) {
+        if state.pos == end {
+            return state.score;
+        }
+
+        if !visited.insert(state.pos) {
+            continue;
+        }
+
+        for next in grid.neighbors4(state.pos) {
+            if !visited.contains(&next) && !grid[next] {
+                // println!("pushing: {}", next);
+                stack.push(State {
+                    pos: next,
+                    score: state.score + 1,
+                });
+            }
+        }
+    }
+
+    -1
+}
+
+fn bfs(grid: &Grid<bool>, start: Point, end: Point) -> i32 {
+    let mut visited = HashSet::new();
+    let state = State {
+        pos: start,
+        score: 0,
+    };
+
+    let mut stack = VecDeque::new();
+    stack.push_back(state);
+
+    while let Some(state) = stack.pop_front() {
+        if state.pos == end {
+            return state.score;
+        }
+
+        if !visited.insert(state.pos) {
+            continue;
+        }
+
+        for next in grid.neighbors4(state.pos) {
+            if !visited.contains(&next) && !grid[next] {
+                // println!("pushing: {}", next);
+                stack.push_back(State {
+                    pos: next,
+                    score: state.score + 1,
+                });
+            }
+        }
+    }
+
+    -1
+}
+
+fn min_score(stack: &mut Vec<State>) -> Option<State> {
+    if stack.is_empty() {
+        return None;
+    }
+
+    let mut min = i32::MAX;
+    let mut index = usize::MAX;
+    for (i, s) in stack.iter().enumerate() {
+        if s.score < min {
+            min = s.score;
+            index = i;
+        }
+    }
+
+    Some(stack.remove(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_outside_the_memory_space_are_errors() {
+        let bytes = parse("5,4\n4,7\n").unwrap();
+        let e = part1(&bytes, 6, 2).unwrap_err().to_string();
+        assert_eq!(e, "byte 4,7 is outside the 7x7 memory space");
+        assert!(part2(&bytes, 6).is_err());
+    }
+}
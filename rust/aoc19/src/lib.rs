@@ -0,0 +1,101 @@
+use aoc_common::{parse, Answer, Params, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Towels;
+
+    fn parse(&self, input: &str) -> Result<Towels> {
+        parse(input)
+    }
+
+    fn part1(&self, towels: &Towels, _params: &Params) -> Result<Answer> {
+        part1(towels)
+    }
+
+    fn part2(&self, towels: &Towels, _params: &Params) -> Result<Answer> {
+        part2(towels)
+    }
+}
+
+/// The available towel patterns and the designs to make from them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Towels {
+    pub patterns: Vec<String>,
+    pub designs: Vec<String>,
+}
+
+pub fn parse(input: &str) -> Result<Towels> {
+    let [mut a, d] = parse::sections(input)?;
+
+    let patterns = a.separated(", ", |c| Ok(c.word()?.to_string()))?;
+    a.end()?;
+
+    let designs = d
+        .lines()
+        .map(|mut line| {
+            let design = line.word()?.to_string();
+            line.end()?;
+            Ok(design)
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Towels { patterns, designs })
+}
+
+fn part1(towels: &Towels) -> Result<Answer> {
+    let available: HashSet<&str> = towels.patterns.iter().map(String::as_str).collect();
+
+    let mut count = 0;
+    for d in &towels.designs {
+        let mut dp = vec![false; d.len() + 1];
+        dp[0] = true;
+
+        if is_possible(&available, d, &mut dp) {
+            // println!("{} is possible", d);
+            count += 1;
+        }
+    }
+
+    Ok(count.into())
+}
+
+fn part2(towels: &Towels) -> Result<Answer> {
+    let available: HashSet<&str> = towels.patterns.iter().map(String::as_str).collect();
+
+    let mut count = 0;
+    for d in &towels.designs {
+        let mut dp = vec![0; d.len() + 1];
+        dp[0] = 1;
+
+        count += all_possible(&available, d, &mut dp);
+    }
+
+    Ok(count.into())
+}
+
+fn is_possible(available: &HashSet<&str>, design: &str, dp: &mut [bool]) -> bool {
+    for i in 1..=design.len() {
+        for j in 0..i {
+            if dp[j] && available.contains(&design[j..i]) {
+                dp[i] = true;
+                break;
+            }
+        }
+    }
+
+    dp[design.len()]
+}
+
+fn all_possible(available: &HashSet<&str>, design: &str, dp: &mut [i64]) -> i64 {
+    for i in 1..=design.len() {
+        for j in 0..i {
+            if dp[j] > 0 && available.contains(&design[j..i]) {
+                dp[i] += dp[j];
+            }
+        }
+    }
+
+    dp[design.len()]
+}
@@ -0,0 +1,202 @@
+use aoc_common::{debug, Answer, Grid, Param, Params, Point, Result, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Grid<char>;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "threshold",
+        default: 100,
+        min: 0,
+        max: i64::MAX,
+        help: "Picoseconds a cheat must save to be counted",
+    }];
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Grid<char>, params: &Params) -> Result<Answer> {
+        part1(grid, params.get("threshold")?)
+    }
+
+    fn part2(&self, grid: &Grid<char>, params: &Params) -> Result<Answer> {
+        part2(grid, params.get("threshold")?)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input)
+}
+
+fn part1(grid: &Grid<char>, threshold: i64) -> Result<Answer> {
+    let start = grid.find(&'S').ok_or("track has no start")?;
+    let end = grid.find(&'E').ok_or("track has no end")?;
+
+    let base_time = bfs(grid, start, end);
+    // println!("{}", base_time);
+
+    let mut dist_from_start = Grid::new(grid.rows(), grid.cols(), usize::MAX);
+    let mut dist_to_end = Grid::new(grid.rows(), grid.cols(), usize::MAX);
+
+    for p in grid.positions(|c| *c != '#') {
+        dist_from_start[p] = bfs(grid, start, p);
+        dist_to_end[p] = bfs(grid, end, p);
+    }
+
+    let mut count = 0;
+    for p in grid.points() {
+        let savings = check_cheats(
+            grid,
+            p,
+            &dist_from_start,
+            &dist_to_end,
+            base_time as i64,
+            threshold,
+        );
+        if savings > 0 {
+            count += savings;
+        }
+    }
+
+    Ok(count.into())
+}
+
+fn part2(grid: &Grid<char>, threshold: usize) -> Result<Answer> {
+    let start = grid.find(&'S').ok_or("track has no start")?;
+    let end = grid.find(&'E').ok_or("track has no end")?;
+    let path: HashSet<Point> = grid.positions(|c| *c != '#').collect();
+
+    let count = cheats_bfs(&path, start, end, threshold);
+
+    Ok(count.into())
+}
+
+fn check_cheats(
+    grid: &Grid<char>,
+    start: Point,
+    dist_from_start: &Grid<usize>,
+    dist_to_end: &Grid<usize>,
+    base_time: i64,
+    diff_limit: i64,
+) -> i64 {
+    let mut count = 0;
+    if dist_from_start[start] == usize::MAX || dist_to_end[start] == usize::MAX {
+        return -1;
+    }
+
+    for next in grid.neighbors4(start) {
+        if is_valid_track(grid, next) {
+            let cheat_time = dist_from_start[start] + 1 + dist_to_end[next];
+            let time_saved = base_time - cheat_time as i64;
+            if time_saved >= diff_limit {
+                // println!("1-step: {}: {}", start, time_saved);
+                count += 1
+            }
+        }
+    }
+
+    for inext in grid.neighbors4(start) {
+        for enext in grid.neighbors4(inext) {
+            if is_valid_track(grid, enext) {
+                let cheat_time = dist_from_start[start] + 2 + dist_to_end[enext];
+                let time_saved = base_time - cheat_time as i64;
+                if time_saved >= diff_limit {
+                    // println!("2-step: {}: {}", start, time_saved);
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+fn is_valid_track(grid: &Grid<char>, pos: Point) -> bool {
+    grid[pos] != '#'
+}
+
+fn bfs(grid: &Grid<char>, start: Point, end: Point) -> usize {
+    let mut queue = vec![(start, 0)];
+    let mut visited = HashSet::new();
+
+    while let Some((pos, time)) = queue.pop() {
+        if pos == end {
+            return time;
+        }
+
+        if !visited.insert(pos) {
+            continue;
+        }
+
+        for next in grid.neighbors4(pos) {
+            if is_valid_track(grid, next) {
+                queue.push((next, time + 1));
+            }
+        }
+    }
+
+    0
+}
+
+fn cheats_bfs(path: &HashSet<Point>, start: Point, end: Point, limit: usize) -> usize {
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut visited = HashSet::new();
+
+    let mut dist: HashMap<Point, usize> = HashMap::new();
+    let mut backlink: HashMap<Point, Point> = HashMap::new();
+
+    while let Some((pos, time)) = queue.pop_front() {
+        if pos == end {
+            break;
+        }
+
+        if !visited.insert(pos) {
+            continue;
+        }
+
+        for next in pos.neighbors4() {
+            let next_time = time + 1;
+            if !path.contains(&next) {
+                continue;
+            }
+
+            if !visited.contains(&next) {
+                let entry = dist.entry(pos).or_insert(usize::MAX);
+                if next_time < *entry {
+                    *entry = next_time;
+                    backlink.insert(next, pos);
+                }
+            }
+            queue.push_back((next, next_time + 1));
+        }
+    }
+
+    let mut path = VecDeque::from([end]);
+    let mut current = end;
+    while let Some(entry) = backlink.get(&current) {
+        path.push_front(*entry);
+        current = *entry;
+    }
+
+    debug!("{:?}", path.len());
+
+    let mut cheats: HashMap<usize, usize> = HashMap::new();
+    for i in 0..path.len() - 1 {
+        for j in i + 1..path.len() {
+            let dist = path[i].manhattan(path[j]) as usize;
+            if dist < 21 && (j - i) > dist {
+                *cheats.entry((j - i) - dist).or_default() += 1;
+            }
+        }
+    }
+
+    let count: usize = cheats
+        .iter()
+        .filter_map(|(k, v)| if *k >= limit { Some(v) } else { None })
+        .sum();
+
+    count
+}
@@ -0,0 +1,206 @@
+//! Keypad layouts, written one row per line with a character per key:
+//!
+//! ```text
+//! 789
+//! 456
+//! 123
+//! #0A
+//! ```
+//!
+//! `#` or a space marks a gap, where there is no key and a robot arm must
+//! never point. Spaces are kept wherever they are, so a gap can end a row
+//! and a row can be all gaps; only empty lines are skipped. A row shorter
+//! than the widest ends in gaps. Every arm starts on `A`, so each keypad
+//! needs one, and an arm must be able to reach every key from every other.
+
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{Grid, Point, Result};
+
+/// The door's numeric keypad.
+pub const NUMERIC: &str = "789\n456\n123\n#0A";
+/// The keypad that moves a robot arm.
+pub const DIRECTIONAL: &str = "#^A\n<v>";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keypad {
+    /// The key at each position, or `None` for a gap.
+    pub grid: Grid<Option<char>>,
+}
+
+impl Keypad {
+    pub fn parse(text: &str) -> Result<Keypad> {
+        let mut rows: Vec<Vec<Option<char>>> = text
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.chars()
+                    .map(|c| match c {
+                        '#' | ' ' => None,
+                        c => Some(c),
+                    })
+                    .collect()
+            })
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, None);
+        }
+        let grid = Grid::from_rows(rows)?;
+
+        let mut keys = HashSet::new();
+        for (_, key) in grid.iter() {
+            if let Some(key) = key {
+                if !keys.insert(*key) {
+                    return Err(format!("the keypad has two {} keys", key).into());
+                }
+            }
+        }
+        if !keys.contains(&'A') {
+            return Err("the keypad has no A key".into());
+        }
+
+        let keypad = Keypad { grid };
+        let start = keypad.find('A').expect("the keypad has an A key");
+        let distances = keypad.distances(start);
+        if let Some((_, Some(key))) = keypad
+            .grid
+            .iter()
+            .find(|(p, key)| key.is_some() && distances[*p].is_none())
+        {
+            return Err(format!("the keypad has no way from A to {} around its gaps", key).into());
+        }
+        Ok(keypad)
+    }
+
+    pub fn numeric() -> Keypad {
+        Keypad::parse(NUMERIC).expect("the numeric layout is valid")
+    }
+
+    pub fn directional() -> Keypad {
+        Keypad::parse(DIRECTIONAL).expect("the directional layout is valid")
+    }
+
+    /// Where `key` is, if the keypad has it.
+    pub fn find(&self, key: char) -> Option<Point> {
+        self.grid.find(&Some(key))
+    }
+
+    /// Whether an arm can point at `p`.
+    pub fn is_key(&self, p: Point) -> bool {
+        matches!(self.grid.get(p), Some(Some(_)))
+    }
+
+    /// The fewest moves from `from` to each key without pointing at a gap,
+    /// or `None` where there is no way.
+    pub fn distances(&self, from: Point) -> Grid<Option<usize>> {
+        let mut distances = Grid::new(self.grid.rows(), self.grid.cols(), None);
+        distances[from] = Some(0);
+        let mut queue = VecDeque::from([(from, 0)]);
+        while let Some((p, d)) = queue.pop_front() {
+            for next in p.neighbors4() {
+                if self.is_key(next) && distances[next].is_none() {
+                    distances[next] = Some(d + 1);
+                    queue.push_back((next, d + 1));
+                }
+            }
+        }
+        distances
+    }
+}
+
+/// A stack of keypads separated by lines starting with `--`, which no
+/// keypad row can, since that would be two `-` keys:
+///
+/// ```text
+/// 789
+/// 456
+/// 123
+/// #0A
+/// ---
+/// #^A
+/// <v>
+/// ```
+///
+/// First comes the keypad the code is typed on, then each keypad that drives
+/// the arm at the one before it, and last your own. So every keypad after
+/// the first needs `^`, `v`, `<` and `>` keys.
+pub fn parse_stack(text: &str) -> Result<Vec<Keypad>> {
+    let mut layouts = vec![String::new()];
+    for line in text.lines() {
+        if line.starts_with("--") {
+            layouts.push(String::new());
+        } else {
+            let layout = layouts.last_mut().expect("there is always a layout");
+            *layout += line;
+            *layout += "\n";
+        }
+    }
+    let keypads = layouts
+        .iter()
+        .enumerate()
+        .map(|(i, layout)| Keypad::parse(layout).map_err(|e| format!("keypad {}: {}", i + 1, e)))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    check_stack(&keypads)?;
+    Ok(keypads)
+}
+
+/// Checks that each keypad after the first can drive the arm before it.
+pub fn check_stack(keypads: &[Keypad]) -> Result<()> {
+    if keypads.is_empty() {
+        return Err("there are no keypads".into());
+    }
+    for (i, keypad) in keypads.iter().enumerate().skip(1) {
+        if let Some(key) = ['^', 'v', '<', '>']
+            .into_iter()
+            .find(|&k| keypad.find(k).is_none())
+        {
+            return Err(format!("keypad {} drives an arm but has no {} key", i + 1, key).into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailing_gaps_are_kept() {
+        let keypad = Keypad::parse("^A \n<v>\n").unwrap();
+        assert_eq!((keypad.grid.rows(), keypad.grid.cols()), (2, 3));
+        assert!(!keypad.is_key(Point::new(0, 2)));
+        assert_eq!(keypad.find('>'), Some(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn rows_can_be_all_gaps() {
+        let e = Keypad::parse("1 A\n   \n2 3\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "the keypad has no way from A to 1 around its gaps"
+        );
+
+        let keypad = Keypad::parse("12A\n   \n").unwrap();
+        assert_eq!(keypad.grid.rows(), 2);
+        assert!(!keypad.is_key(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn short_rows_end_in_gaps() {
+        let keypad = Keypad::parse("12A\n4\n").unwrap();
+        assert!(keypad.is_key(Point::new(1, 0)));
+        assert!(!keypad.is_key(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn stacks_split_on_dashes() {
+        let stack = parse_stack("12A\n   \n\n---\n^A \n<v>\n---\n#^A\n<v>\n").unwrap();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack[0].grid.rows(), 2);
+        assert_eq!(stack[1], Keypad::parse("^A \n<v>\n").unwrap());
+
+        let e = parse_stack("12A\n---\n^A\n<v>\n---\n1\n").unwrap_err();
+        assert_eq!(e.to_string(), "keypad 3: the keypad has no A key");
+    }
+}
@@ -0,0 +1,292 @@
+use aoc_common::{debug, parse, Answer, Direction, Grid, Params, Point, Result, Solution};
+use keypad::Keypad;
+use std::collections::HashMap;
+
+pub mod keypad;
+pub mod replay;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Code>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Code>> {
+        parse(input)
+    }
+
+    fn part1(&self, codes: &Vec<Code>, _params: &Params) -> Result<Answer> {
+        part1(codes)
+    }
+
+    fn part2(&self, codes: &Vec<Code>, _params: &Params) -> Result<Answer> {
+        part2(codes)
+    }
+}
+
+/// A door code such as `029A`, along with its numeric part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Code {
+    pub keys: String,
+    pub value: usize,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Code>> {
+    parse::lines(input)
+        .map(|mut line| {
+            let keys = line.rest().trim_end().to_string();
+            let value = line.number()?;
+            line.expect("A")?;
+            line.end()?;
+            Ok(Code { keys, value })
+        })
+        .collect()
+}
+
+fn part1(codes: &[Code]) -> Result<Answer> {
+    complexity(codes, 2)
+}
+
+/// The same as part 1, but with 25 robots at directional keypads. The
+/// sequences are far too long to build, so only their lengths are counted.
+fn part2(codes: &[Code]) -> Result<Answer> {
+    complexity(codes, 25)
+}
+
+/// The sum over the codes of your presses times the code's numeric part.
+fn complexity(codes: &[Code], robots: usize) -> Result<Answer> {
+    let mut chain = Chain::puzzle(robots);
+    let mut count = 0;
+    for Code { keys, value } in codes {
+        let presses = chain.code(keys)?;
+        debug!("{}: {} * {}", keys, presses, value);
+        count += presses * value;
+    }
+    Ok(count.into())
+}
+
+/// Counts the presses on the last of a stack of keypads. Each keypad after
+/// the first drives the arm at the one before it. Every press on a keypad
+/// starts and ends with the arms further down the stack on A, so the cost of
+/// moving from one key to another and pressing it only depends on the two
+/// keys and the keypad, and the sequences never need to be built.
+pub struct Chain {
+    keypads: Vec<Keypad>,
+    /// The presses for (keypad, from, to).
+    memo: HashMap<(usize, char, char), usize>,
+}
+
+impl Chain {
+    /// A chain for the stack described in [`keypad::parse_stack`].
+    pub fn new(keypads: Vec<Keypad>) -> Result<Chain> {
+        keypad::check_stack(&keypads)?;
+        Ok(Chain {
+            keypads,
+            memo: HashMap::new(),
+        })
+    }
+
+    /// The puzzle's chain: the door's numeric keypad, `robots` directional
+    /// keypads used by robots and the one you use.
+    pub fn puzzle(robots: usize) -> Chain {
+        let mut keypads = vec![Keypad::numeric()];
+        keypads.extend(std::iter::repeat_n(Keypad::directional(), robots + 1));
+        Chain::new(keypads).expect("the puzzle's keypads form a chain")
+    }
+
+    pub fn keypads(&self) -> &[Keypad] {
+        &self.keypads
+    }
+
+    /// Your fewest presses to type `keys` on the first keypad.
+    pub fn code(&mut self, keys: &str) -> Result<usize> {
+        self.sequence(keys, 0)
+    }
+
+    /// One of your shortest sequences of presses to type `keys` on the first
+    /// keypad. Its length grows exponentially with the stack.
+    pub fn presses(&mut self, keys: &str) -> Result<String> {
+        self.expand(keys, 0)
+    }
+
+    /// Your presses for the arm at keypad `level` to press `keys`.
+    fn sequence(&mut self, keys: &str, level: usize) -> Result<usize> {
+        if level == self.keypads.len() - 1 {
+            return Ok(keys.chars().count());
+        }
+        let mut from = 'A';
+        let mut count = 0;
+        for to in keys.chars() {
+            count += self.cost(level, from, to)?;
+            from = to;
+        }
+        Ok(count)
+    }
+
+    /// Your presses for the arm at keypad `level` to move from `from` to
+    /// `to` and press it.
+    fn cost(&mut self, level: usize, from: char, to: char) -> Result<usize> {
+        if let Some(&count) = self.memo.get(&(level, from, to)) {
+            return Ok(count);
+        }
+        let (_, count) = self.best_route(level, from, to)?;
+        self.memo.insert((level, from, to), count);
+        Ok(count)
+    }
+
+    /// The cheapest of the shortest routes from `from` to `to` at keypad
+    /// `level`, and what it costs you. Routes of the same length can differ
+    /// a lot further up the stack, since repeating a key is cheap there.
+    fn best_route(&mut self, level: usize, from: char, to: char) -> Result<(String, usize)> {
+        let keypad = &self.keypads[level];
+        let missing = |key| format!("keypad {} has no {} key", level + 1, key);
+        let start = keypad.find(from).ok_or_else(|| missing(from))?;
+        let end = keypad.find(to).ok_or_else(|| missing(to))?;
+
+        let mut best: Option<(String, usize)> = None;
+        for route in routes(keypad, start, end) {
+            let count = self.sequence(&route, level + 1)?;
+            if best.as_ref().is_none_or(|(_, c)| count < *c) {
+                best = Some((route, count));
+            }
+        }
+        best.ok_or_else(|| {
+            format!(
+                "keypad {} has no way from {} to {} around its gaps",
+                level + 1,
+                from,
+                to
+            )
+            .into()
+        })
+    }
+
+    /// Your presses, written out, for the arm at keypad `level` to press
+    /// `keys`.
+    fn expand(&mut self, keys: &str, level: usize) -> Result<String> {
+        if level == self.keypads.len() - 1 {
+            return Ok(keys.to_string());
+        }
+        let mut from = 'A';
+        let mut presses = String::new();
+        for to in keys.chars() {
+            let (route, _) = self.best_route(level, from, to)?;
+            presses += &self.expand(&route, level + 1)?;
+            from = to;
+        }
+        Ok(presses)
+    }
+}
+
+/// Every shortest way to move an arm from `from` to `to` without pointing at
+/// a gap, each followed by the press. A route may have to step away from
+/// `to` to get around a gap.
+pub fn routes(keypad: &Keypad, from: Point, to: Point) -> Vec<String> {
+    fn walk(
+        keypad: &Keypad,
+        distances: &Grid<Option<usize>>,
+        at: Point,
+        moves: &mut String,
+        routes: &mut Vec<String>,
+    ) {
+        let Some(left) = distances[at] else {
+            return;
+        };
+        if left == 0 {
+            routes.push(format!("{}A", moves));
+            return;
+        }
+        // Vertical moves are tried first, so routes come out in the same
+        // order for the same layout.
+        for dir in [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ] {
+            let next = at.step(dir);
+            if keypad.is_key(next) && distances[next] == Some(left - 1) {
+                moves.push(dir.to_char());
+                walk(keypad, distances, next, moves, routes);
+                moves.pop();
+            }
+        }
+    }
+
+    let mut routes = vec![];
+    if keypad.is_key(from) {
+        let distances = keypad.distances(to);
+        walk(keypad, &distances, from, &mut String::new(), &mut routes);
+    }
+    routes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "029A\n980A\n179A\n456A\n379A\n";
+
+    #[test]
+    fn routes_avoid_the_gap() {
+        let numeric = Keypad::numeric();
+        let (a, one) = (numeric.find('A').unwrap(), numeric.find('1').unwrap());
+        assert_eq!(routes(&numeric, a, one), ["^<<A", "<^<A"]);
+        assert_eq!(routes(&numeric, one, a), [">v>A", ">>vA"]);
+        assert_eq!(routes(&numeric, a, a), ["A"]);
+    }
+
+    #[test]
+    fn routes_detour_around_gaps() {
+        // 1 and 2 are only joined through the row below them.
+        let keypad = Keypad::parse("1#2\n345\nA##\n").unwrap();
+        let (one, two) = (keypad.find('1').unwrap(), keypad.find('2').unwrap());
+        assert_eq!(routes(&keypad, one, two), ["v>>^A"]);
+
+        let stack = keypad::parse_stack("1#2\n345\nA##\n---\n#^A\n<v>\n").unwrap();
+        let mut chain = Chain::new(stack).unwrap();
+        let presses = chain.presses("12A").unwrap();
+        assert_eq!(presses, "^^Av>>^Av<<vA");
+        assert_eq!(presses.len(), chain.code("12A").unwrap());
+        let frames = replay::replay(chain.keypads(), &presses).unwrap();
+        assert_eq!(frames.last().unwrap().typed, "12A");
+    }
+
+    #[test]
+    fn sample_code_costs() {
+        let mut chain = Chain::puzzle(2);
+        let costs: Vec<usize> = parse(SAMPLE)
+            .unwrap()
+            .iter()
+            .map(|c| chain.code(&c.keys).unwrap())
+            .collect();
+        assert_eq!(costs, [68, 60, 68, 64, 64]);
+        assert_eq!(
+            part1(&parse(SAMPLE).unwrap()).unwrap(),
+            Answer::from(126384)
+        );
+    }
+
+    #[test]
+    fn built_sequences_type_the_code_at_the_counted_length() {
+        for code in parse(SAMPLE).unwrap() {
+            for robots in 0..=3 {
+                let mut chain = Chain::puzzle(robots);
+                let presses = chain.presses(&code.keys).unwrap();
+                assert_eq!(presses.len(), chain.code(&code.keys).unwrap());
+                let frames = replay::replay(chain.keypads(), &presses).unwrap();
+                assert_eq!(frames.last().unwrap().typed, code.keys);
+            }
+        }
+    }
+
+    #[test]
+    fn stacks_come_from_text() {
+        let stack = keypad::parse_stack("123\n456\n789\n#0A\n---\n#^A\n<v>\n").unwrap();
+        assert_eq!(Chain::new(stack).unwrap().code("0A").unwrap(), 4);
+
+        let e = keypad::parse_stack("12A\n---\n^A\n")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(e, "keypad 2 drives an arm but has no v key");
+    }
+}
@@ -0,0 +1,236 @@
+//! Replays a sequence of your presses through a stack of keypads, one press
+//! at a time, recording where every arm points and which keys get pressed.
+//! The frames can be drawn as the keypads themselves or written as a log
+//! with a line per press.
+
+use aoc_common::{Direction, Point, Result};
+
+use crate::keypad::Keypad;
+
+/// The stack just after one of your presses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Where each keypad's arm points, first keypad first. Your own keypad
+    /// has no arm, so its entry is the key you pressed.
+    pub arms: Vec<Point>,
+    /// The key pressed on each keypad by this press, if any. Yours is always
+    /// pressed, and a press only reaches a keypad through an `A` above it.
+    pub pressed: Vec<Option<char>>,
+    /// Everything typed on the first keypad so far.
+    pub typed: String,
+}
+
+/// A frame per press in `presses`, which are typed on the last keypad. Every
+/// arm starts on `A`.
+pub fn replay(keypads: &[Keypad], presses: &str) -> Result<Vec<Frame>> {
+    let last = keypads.len().checked_sub(1).ok_or("there are no keypads")?;
+    let mut arms: Vec<Point> = keypads
+        .iter()
+        .map(|k| k.find('A').expect("every keypad has an A key"))
+        .collect();
+    let mut typed = String::new();
+
+    let mut frames = vec![];
+    for (step, key) in presses.chars().enumerate() {
+        arms[last] = keypads[last]
+            .find(key)
+            .ok_or_else(|| format!("keypad {} has no {} key", last + 1, key))?;
+        let mut pressed = vec![None; keypads.len()];
+        pressed[last] = Some(key);
+
+        // Follow the press down the stack until it moves an arm, or reaches
+        // the first keypad.
+        let mut level = last;
+        while level > 0 {
+            let key = pressed[level].expect("the press reached this keypad");
+            let below = level - 1;
+            if key == 'A' {
+                pressed[below] = keypads[below].grid[arms[below]];
+                level = below;
+                continue;
+            }
+            let dir = Direction::from_char(key).ok_or_else(|| {
+                format!(
+                    "press {}: {} on keypad {} does not move an arm",
+                    step + 1,
+                    key,
+                    level + 1
+                )
+            })?;
+            let p = arms[below].step(dir);
+            if !keypads[below].is_key(p) {
+                return Err(format!(
+                    "press {}: the arm at keypad {} points at a gap",
+                    step + 1,
+                    below + 1
+                )
+                .into());
+            }
+            arms[below] = p;
+            break;
+        }
+        if let Some(key) = pressed[0] {
+            typed.push(key);
+        }
+
+        frames.push(Frame {
+            arms: arms.clone(),
+            pressed,
+            typed: typed.clone(),
+        });
+    }
+    Ok(frames)
+}
+
+/// A line per frame with the key under each arm, your keypad first, and a
+/// `*` after each key that was pressed:
+///
+/// ```text
+/// press  4    3    2    1    typed
+///     1  v*   >    A    A
+///     2  <*   v    A    A
+///     3  A*   v*   >    A
+/// ```
+pub fn log(keypads: &[Keypad], frames: &[Frame]) -> String {
+    let mut out = String::from("press");
+    for level in (0..keypads.len()).rev() {
+        out += &format!("  {:<3}", level + 1);
+    }
+    out += " typed\n";
+
+    for (step, frame) in frames.iter().enumerate() {
+        let mut line = format!("{:>5}", step + 1);
+        for level in (0..keypads.len()).rev() {
+            let key = keypads[level].grid[frame.arms[level]].unwrap_or('#');
+            let mark = match frame.pressed[level] {
+                Some(_) => '*',
+                None => ' ',
+            };
+            line += &format!("  {}{} ", key, mark);
+        }
+        line += &format!(" {}", frame.typed);
+        out += line.trim_end();
+        out += "\n";
+    }
+    out
+}
+
+/// The keypads side by side, yours first, with the key under each arm in
+/// `[]` and a key being pressed in `**`.
+pub fn draw(keypads: &[Keypad], frame: &Frame) -> String {
+    let columns: Vec<Vec<String>> = (0..keypads.len())
+        .rev()
+        .map(|level| {
+            let keypad = &keypads[level];
+            let mut lines = vec![format!("keypad {}", level + 1)];
+            for row in 0..keypad.grid.rows() {
+                let mut line = String::new();
+                for col in 0..keypad.grid.cols() {
+                    let p = Point::new(row as i64, col as i64);
+                    line += &match keypad.grid[p] {
+                        None => "   ".to_string(),
+                        Some(key) if p != frame.arms[level] => format!(" {} ", key),
+                        Some(key) if frame.pressed[level].is_some() => format!("*{}*", key),
+                        Some(key) => format!("[{}]", key),
+                    };
+                }
+                lines.push(line);
+            }
+            lines
+        })
+        .collect();
+
+    let height = columns.iter().map(Vec::len).max().unwrap_or(0);
+    let mut out = String::new();
+    for row in 0..height {
+        let mut line = String::new();
+        for column in &columns {
+            let width = column.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+            let cell = column.get(row).map_or("", String::as_str);
+            line += &format!("{:<width$}    ", cell, width = width);
+        }
+        out += line.trim_end();
+        out += "\n";
+    }
+    out += &format!("\ntyped: {}\n", frame.typed);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Chain;
+
+    /// The frames for typing `0A` through the door's keypad and yours.
+    fn one_robot() -> (Vec<Keypad>, Vec<Frame>) {
+        let mut chain = Chain::puzzle(0);
+        let presses = chain.presses("0A").unwrap();
+        assert_eq!(presses, "<A>A");
+        let frames = replay(chain.keypads(), &presses).unwrap();
+        (chain.keypads().to_vec(), frames)
+    }
+
+    #[test]
+    fn log_marks_every_press() {
+        let (keypads, frames) = one_robot();
+        assert_eq!(
+            log(&keypads, &frames),
+            "\
+press  2    1   typed
+    1  <*   0
+    2  A*   0*  0
+    3  >*   A   0
+    4  A*   A*  0A
+"
+        );
+    }
+
+    #[test]
+    fn draw_shows_the_arms() {
+        let (keypads, frames) = one_robot();
+        // Pressing 0 on the door's keypad.
+        assert_eq!(
+            draw(&keypads, &frames[1]),
+            "\
+keypad 2     keypad 1
+    ^ *A*     7  8  9
+ <  v  >      4  5  6
+              1  2  3
+                *0* A
+
+typed: 0
+"
+        );
+        // Moving its arm on to A.
+        assert_eq!(
+            draw(&keypads, &frames[2]),
+            "\
+keypad 2     keypad 1
+    ^  A      7  8  9
+ <  v *>*     4  5  6
+              1  2  3
+                 0 [A]
+
+typed: 0
+"
+        );
+    }
+
+    #[test]
+    fn arms_cannot_point_at_gaps() {
+        let keypads = [Keypad::numeric(), Keypad::directional()];
+        assert_eq!(
+            replay(&keypads, "<A<").unwrap_err().to_string(),
+            "press 3: the arm at keypad 1 points at a gap"
+        );
+    }
+
+    #[test]
+    fn only_arrows_move_arms() {
+        let keypads = [Keypad::numeric(), Keypad::parse("#^AX\n<v>\n").unwrap()];
+        assert_eq!(
+            replay(&keypads, "<X").unwrap_err().to_string(),
+            "press 2: X on keypad 2 does not move an arm"
+        );
+    }
+}
@@ -0,0 +1,284 @@
+//! Checks a circuit against the shape of a ripple-carry adder, which for each
+//! bit `k` above 0 computes
+//!
+//! ```text
+//! xk XOR yk -> sk        xk AND yk -> gk
+//! sk XOR ck -> zk        sk AND ck -> pk        gk OR pk -> ck+1
+//! ```
+//!
+//! with `c1 = x00 AND y00`, `z00 = x00 XOR y00` and the last carry as the
+//! highest z. A gate whose output wire breaks one of these patterns is the
+//! end of a swapped pair.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{self, Display};
+
+use aoc_common::Result;
+
+use crate::{Circuit, Gate, Op};
+
+/// The part of the adder pattern a gate's output breaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// A sum bit other than the last is not made by an XOR.
+    SumNotXor { z: String, op: Op },
+    /// The last z, the final carry, is not made by an OR.
+    CarryOutNotOr { z: String, op: Op },
+    /// `x00 XOR y00` does not drive `z00`.
+    FirstSumNotZ,
+    /// An XOR of two internal wires, which can only be a sum, does not drive
+    /// a z wire.
+    InnerXorNotSum,
+    /// `xk XOR yk` is not read by an XOR, so it never reaches `zk`.
+    HalfSumUnused { bit: String },
+    /// An AND is not read by an OR, so it never reaches a carry.
+    CarryTermUnused,
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::SumNotXor { z, op } => write!(
+                f,
+                "drives the sum bit {}, which should come from an XOR, not an {}",
+                z, op
+            ),
+            Rule::CarryOutNotOr { z, op } => write!(
+                f,
+                "drives the final carry {}, which should come from an OR, not an {}",
+                z, op
+            ),
+            Rule::FirstSumNotZ => write!(f, "is x00 XOR y00, which should drive z00"),
+            Rule::InnerXorNotSum => write!(
+                f,
+                "is an XOR of two internal wires, which should drive a z wire"
+            ),
+            Rule::HalfSumUnused { bit } => write!(
+                f,
+                "is x{0} XOR y{0}, which should feed the XOR that makes z{0}",
+                bit
+            ),
+            Rule::CarryTermUnused => write!(f, "is an AND, which should feed the OR of a carry"),
+        }
+    }
+}
+
+/// A gate output that breaks the adder pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub wire: String,
+    pub rule: Rule,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.wire, self.rule)
+    }
+}
+
+/// Every violation in the circuit, ordered by wire name. A wire can break
+/// more than one rule. Fails if the circuit's wires are not those of an
+/// adder: x and y bits 0 to n-1 and z bits 0 to n.
+pub fn check(circuit: &Circuit) -> Result<Vec<Violation>> {
+    bits(circuit)?;
+    let mut readers: HashMap<&str, Vec<Op>> = HashMap::new();
+    for g in &circuit.gates {
+        readers.entry(g.input1.as_str()).or_default().push(g.op);
+        readers.entry(g.input2.as_str()).or_default().push(g.op);
+    }
+    let read_by = |g: &Gate, op: Op| {
+        readers
+            .get(g.output.as_str())
+            .is_some_and(|ops| ops.contains(&op))
+    };
+    let last_z = circuit
+        .gates
+        .iter()
+        .map(|g| &g.output)
+        .filter(|w| w.starts_with('z'))
+        .max();
+
+    let mut violations = vec![];
+    for g in &circuit.gates {
+        let mut violation = |rule| {
+            violations.push(Violation {
+                wire: g.output.clone(),
+                rule,
+            })
+        };
+        let z = g.output.starts_with('z');
+        let bit = input_bit(g);
+
+        if Some(&g.output) == last_z {
+            if g.op != Op::Or {
+                violation(Rule::CarryOutNotOr {
+                    z: g.output.clone(),
+                    op: g.op,
+                });
+            }
+        } else if z && g.op != Op::Xor {
+            violation(Rule::SumNotXor {
+                z: g.output.clone(),
+                op: g.op,
+            });
+        }
+
+        match (g.op, bit) {
+            (Op::Xor, Some("00")) => {
+                if g.output != "z00" {
+                    violation(Rule::FirstSumNotZ);
+                }
+            }
+            (Op::Xor, Some(bit)) => {
+                if !read_by(g, Op::Xor) {
+                    violation(Rule::HalfSumUnused {
+                        bit: bit.to_string(),
+                    });
+                }
+            }
+            (Op::Xor, None) => {
+                if !z {
+                    violation(Rule::InnerXorNotSum);
+                }
+            }
+            // The first carry is a plain AND, read like any other carry.
+            (Op::And, Some("00")) => {}
+            (Op::And, _) => {
+                if !read_by(g, Op::Or) {
+                    violation(Rule::CarryTermUnused);
+                }
+            }
+            (Op::Or, _) => {}
+        }
+    }
+
+    violations.sort_by(|a, b| a.wire.cmp(&b.wire));
+    Ok(violations)
+}
+
+/// How many bits the circuit adds, if its x, y and z wires fit an adder.
+pub fn bits(circuit: &Circuit) -> Result<usize> {
+    let numbers = |prefix: char, names: &mut dyn Iterator<Item = &String>| {
+        names
+            .filter_map(|w| w.strip_prefix(prefix)?.parse::<usize>().ok())
+            .collect::<BTreeSet<_>>()
+    };
+    let x = numbers('x', &mut circuit.wires.iter().map(|w| &w.name));
+    let y = numbers('y', &mut circuit.wires.iter().map(|w| &w.name));
+    let z = numbers('z', &mut circuit.gates.iter().map(|g| &g.output));
+
+    let n = x.len();
+    let contiguous = |bits: &BTreeSet<usize>, len: usize| bits.iter().copied().eq(0..len);
+    if n == 0 || !contiguous(&x, n) || !contiguous(&y, n) || !contiguous(&z, n + 1) {
+        return Err(format!(
+            "the circuit is not an adder: it has {} x, {} y and {} z bits, \
+             where n-bit inputs need x and y bits 0 to n-1 and z bits 0 to n",
+            x.len(),
+            y.len(),
+            z.len()
+        )
+        .into());
+    }
+    Ok(n)
+}
+
+/// The swapped wires: each wire with a violation, once, in order.
+pub fn swapped(violations: &[Violation]) -> Vec<&str> {
+    let mut wires: Vec<&str> = violations.iter().map(|v| v.wire.as_str()).collect();
+    wires.dedup();
+    wires
+}
+
+/// The bit number of a gate that reads `xk` and `yk`.
+fn input_bit(g: &Gate) -> Option<&str> {
+    let (a, b) = (g.input1.as_str(), g.input2.as_str());
+    let (x, y) = if a.starts_with('x') { (a, b) } else { (b, a) };
+    match (x.strip_prefix('x'), y.strip_prefix('y')) {
+        (Some(i), Some(j)) if i == j => Some(i),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::adder;
+
+    fn violations(swaps: &[(&str, &str)]) -> Vec<(String, Rule)> {
+        check(&adder(swaps))
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.wire, v.rule))
+            .collect()
+    }
+
+    fn v(wire: &str, rule: Rule) -> (String, Rule) {
+        (wire.to_string(), rule)
+    }
+
+    #[test]
+    fn a_correct_adder_breaks_no_rules() {
+        assert_eq!(violations(&[]), []);
+        assert_eq!(bits(&adder(&[])).unwrap(), 3);
+    }
+
+    #[test]
+    fn a_sum_swapped_with_a_carry() {
+        let z01 = Rule::SumNotXor {
+            z: "z01".to_string(),
+            op: Op::Or,
+        };
+        assert_eq!(
+            violations(&[("z01", "c02")]),
+            [v("c02", Rule::InnerXorNotSum), v("z01", z01)]
+        );
+    }
+
+    #[test]
+    fn the_first_sum_swapped_with_the_first_carry() {
+        let z00 = Rule::SumNotXor {
+            z: "z00".to_string(),
+            op: Op::And,
+        };
+        assert_eq!(
+            violations(&[("z00", "c01")]),
+            [v("c01", Rule::FirstSumNotZ), v("z00", z00)]
+        );
+    }
+
+    #[test]
+    fn a_half_sum_swapped_with_its_carry_term() {
+        let bit = "01".to_string();
+        assert_eq!(
+            violations(&[("s01", "g01")]),
+            [
+                v("g01", Rule::HalfSumUnused { bit }),
+                v("s01", Rule::CarryTermUnused)
+            ]
+        );
+    }
+
+    #[test]
+    fn the_final_carry_swapped_with_a_sum() {
+        let z02 = Rule::SumNotXor {
+            z: "z02".to_string(),
+            op: Op::Or,
+        };
+        let z03 = Rule::CarryOutNotOr {
+            z: "z03".to_string(),
+            op: Op::Xor,
+        };
+        assert_eq!(
+            violations(&[("z02", "z03")]),
+            [v("z02", z02), v("z03", z03)]
+        );
+    }
+
+    #[test]
+    fn other_widths_are_not_adders() {
+        let mut circuit = adder(&[]);
+        circuit.wires.retain(|w| w.name != "y02");
+        let e = check(&circuit).unwrap_err().to_string();
+        assert!(e.contains("it has 3 x, 2 y and 4 z bits"), "{}", e);
+    }
+}
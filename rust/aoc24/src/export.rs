@@ -0,0 +1,310 @@
+//! Writes a circuit out for other tools: as a Graphviz graph to look at, or
+//! as a structural Verilog module and testbench to simulate, e.g. with
+//! iverilog.
+
+use std::collections::BTreeSet;
+
+use crate::{Circuit, Op};
+
+/// A DOT digraph with a node per gate, colored by its operation, and an edge
+/// per wire. The x and y inputs are ranked at the top and the z outputs at
+/// the bottom.
+pub fn dot(circuit: &Circuit) -> String {
+    let mut out = String::new();
+    let (inputs, outputs) = ports(circuit);
+
+    out += "digraph circuit {\n";
+    out += "  rankdir=TB;\n";
+    out += "  node [style=filled, fontname=monospace];\n";
+    out += "  { rank=source;\n";
+    for wire in &inputs {
+        out += &format!("    {} [shape=circle, fillcolor=white];\n", wire);
+    }
+    out += "  }\n";
+    out += "  { rank=sink;\n";
+    for wire in &outputs {
+        out += &format!("    {} [shape=doublecircle, fillcolor=white];\n", wire);
+    }
+    out += "  }\n";
+
+    // Each gate is a node named after its output wire. A z wire is both, so
+    // its gate gets a separate node.
+    let node = |wire: &str| {
+        if outputs.contains(wire) {
+            format!("gate_{}", wire)
+        } else {
+            wire.to_string()
+        }
+    };
+    for g in &circuit.gates {
+        let color = match g.op {
+            Op::And => "lightblue",
+            Op::Or => "palegreen",
+            Op::Xor => "lightsalmon",
+        };
+        out += &format!(
+            "  {} [shape=box, label=\"{}\\n{}\", fillcolor={}];\n",
+            node(&g.output),
+            g.op,
+            g.output,
+            color
+        );
+        out += &format!("  {} -> {};\n", node(&g.input1), node(&g.output));
+        out += &format!("  {} -> {};\n", node(&g.input2), node(&g.output));
+        if outputs.contains(g.output.as_str()) {
+            out += &format!("  {} -> {};\n", node(&g.output), g.output);
+        }
+    }
+    out += "}\n";
+    out
+}
+
+/// A Verilog module with the x and y wires as inputs, the z wires as
+/// outputs and one primitive gate per gate.
+pub fn verilog(circuit: &Circuit, name: &str) -> String {
+    let mut out = String::new();
+    let (inputs, outputs) = ports(circuit);
+    let ports: Vec<&str> = inputs.iter().chain(&outputs).copied().collect();
+
+    out += &format!("module {}(\n", name);
+    let ports: Vec<String> = ports.into_iter().map(ident).collect();
+    out += &format!("  {}\n", ports.join(",\n  "));
+    out += ");\n";
+    for wire in &inputs {
+        out += &format!("  input {};\n", ident(wire));
+    }
+    for wire in &outputs {
+        out += &format!("  output {};\n", ident(wire));
+    }
+
+    let internal: BTreeSet<&str> = circuit
+        .gates
+        .iter()
+        .map(|g| g.output.as_str())
+        .filter(|w| !outputs.contains(w))
+        .collect();
+    for wire in &internal {
+        out += &format!("  wire {};\n", ident(wire));
+    }
+
+    out += "\n";
+    for (i, g) in circuit.gates.iter().enumerate() {
+        let primitive = match g.op {
+            Op::And => "and",
+            Op::Or => "or",
+            Op::Xor => "xor",
+        };
+        out += &format!(
+            "  {} gate_{}({}, {}, {});\n",
+            primitive,
+            i,
+            ident(&g.output),
+            ident(&g.input1),
+            ident(&g.input2)
+        );
+    }
+    out += "endmodule\n";
+    out
+}
+
+/// A testbench for the [`verilog`] module that applies the circuit's initial
+/// wire values and prints the z outputs as a decimal number, which is the
+/// part 1 answer.
+pub fn testbench(circuit: &Circuit, name: &str) -> String {
+    let mut out = String::new();
+    let (inputs, outputs) = ports(circuit);
+    let connect = |wires: &BTreeSet<&str>| -> Vec<String> {
+        wires
+            .iter()
+            .map(|w| format!(".{0}({0})", ident(w)))
+            .collect()
+    };
+    let mut connections = connect(&inputs);
+    connections.extend(connect(&outputs));
+    let z: Vec<String> = outputs.iter().rev().map(|w| ident(w)).collect();
+
+    out += &format!("module {}_tb;\n", name);
+    for wire in &inputs {
+        out += &format!("  reg {};\n", ident(wire));
+    }
+    for wire in &outputs {
+        out += &format!("  wire {};\n", ident(wire));
+    }
+    out += &format!(
+        "\n  {} device_under_test(\n    {}\n  );\n\n",
+        name,
+        connections.join(",\n    ")
+    );
+    out += "  initial begin\n";
+    for w in &circuit.wires {
+        out += &format!("    {} = {};\n", ident(&w.name), u8::from(w.value));
+    }
+    out += &format!("    #1 $display(\"%0d\", {{{}}});\n", z.join(", "));
+    out += "    $finish;\n";
+    out += "  end\n";
+    out += "endmodule\n";
+    out
+}
+
+/// Verilog's reserved words, which a wire may happen to be named.
+const KEYWORDS: &str = "\
+     always and assign automatic begin buf bufif0 bufif1 case casex casez cell cmos \
+     config deassign default defparam design disable edge else end endcase endconfig \
+     endfunction endgenerate endmodule endprimitive endspecify endtable endtask \
+     event for force forever fork function generate genvar highz0 highz1 if ifnone \
+     incdir include initial inout input instance integer join large liblist library \
+     localparam macromodule medium module nand negedge nmos nor noshowcancelled not \
+     notif0 notif1 or output parameter pmos posedge primitive pull0 pull1 pulldown \
+     pullup pulsestyle_ondetect pulsestyle_onevent rcmos real realtime reg release \
+     repeat rnmos rpmos rtran rtranif0 rtranif1 scalared showcancelled signed small \
+     specify specparam strong0 strong1 supply0 supply1 table task time tran tranif0 \
+     tranif1 tri tri0 tri1 triand trior trireg unsigned use uwire vectored wait wand \
+     weak0 weak1 while wire wor xnor xor";
+
+/// A wire name as a Verilog identifier. Names that are reserved words or
+/// start with a digit are escaped, which takes a trailing space. Wire names
+/// never contain `_`, so the instance names made up here cannot clash.
+fn ident(wire: &str) -> String {
+    if KEYWORDS.split_whitespace().any(|k| k == wire)
+        || wire.starts_with(|c: char| c.is_ascii_digit())
+    {
+        format!("\\{} ", wire)
+    } else {
+        wire.to_string()
+    }
+}
+
+/// The circuit's input wires, x then y, and its z outputs, in order.
+fn ports(circuit: &Circuit) -> (BTreeSet<&str>, BTreeSet<&str>) {
+    let mut inputs = BTreeSet::new();
+    for g in &circuit.gates {
+        for wire in [&g.input1, &g.input2] {
+            if wire.starts_with('x') || wire.starts_with('y') {
+                inputs.insert(wire.as_str());
+            }
+        }
+    }
+    // Inputs that no gate reads are still part of the interface.
+    for w in &circuit.wires {
+        inputs.insert(w.name.as_str());
+    }
+    let outputs = circuit
+        .gates
+        .iter()
+        .map(|g| g.output.as_str())
+        .filter(|w| w.starts_with('z'))
+        .collect();
+    (inputs, outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    /// x + y for two bits, with z02 as the carry out.
+    fn two_bit() -> Circuit {
+        parse(
+            "x00: 1\nx01: 0\ny00: 1\ny01: 1\n\n\
+             x00 XOR y00 -> z00\nx00 AND y00 -> c01\n\
+             x01 XOR y01 -> s01\ns01 XOR c01 -> z01\n\
+             x01 AND y01 -> g01\ns01 AND c01 -> p01\n\
+             g01 OR p01 -> z02\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn dot_ranks_the_ports_and_colors_the_gates() {
+        let dot = dot(&two_bit());
+        assert!(dot.starts_with("digraph circuit {\n"));
+        assert!(dot.contains(
+            "  { rank=source;\n\
+             \x20   x00 [shape=circle, fillcolor=white];\n\
+             \x20   x01 [shape=circle, fillcolor=white];\n\
+             \x20   y00 [shape=circle, fillcolor=white];\n\
+             \x20   y01 [shape=circle, fillcolor=white];\n\
+             \x20 }\n"
+        ));
+        assert!(dot.contains(
+            "  { rank=sink;\n\
+             \x20   z00 [shape=doublecircle, fillcolor=white];\n\
+             \x20   z01 [shape=doublecircle, fillcolor=white];\n\
+             \x20   z02 [shape=doublecircle, fillcolor=white];\n\
+             \x20 }\n"
+        ));
+        for line in [
+            "  c01 [shape=box, label=\"AND\\nc01\", fillcolor=lightblue];",
+            "  s01 [shape=box, label=\"XOR\\ns01\", fillcolor=lightsalmon];",
+            "  gate_z02 [shape=box, label=\"OR\\nz02\", fillcolor=palegreen];",
+            "  x00 -> gate_z00;",
+            "  c01 -> gate_z01;",
+            "  gate_z01 -> z01;",
+        ] {
+            assert!(dot.contains(&format!("{}\n", line)), "{}", line);
+        }
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn verilog_module_ports_and_gates() {
+        assert_eq!(
+            verilog(&two_bit(), "adder"),
+            "\
+module adder(
+  x00,
+  x01,
+  y00,
+  y01,
+  z00,
+  z01,
+  z02
+);
+  input x00;
+  input x01;
+  input y00;
+  input y01;
+  output z00;
+  output z01;
+  output z02;
+  wire c01;
+  wire g01;
+  wire p01;
+  wire s01;
+
+  xor gate_0(z00, x00, y00);
+  and gate_1(c01, x00, y00);
+  xor gate_2(s01, x01, y01);
+  xor gate_3(z01, s01, c01);
+  and gate_4(g01, x01, y01);
+  and gate_5(p01, s01, c01);
+  or gate_6(z02, g01, p01);
+endmodule
+"
+        );
+    }
+
+    #[test]
+    fn testbench_sets_the_inputs_and_prints_z() {
+        let bench = testbench(&two_bit(), "adder");
+        assert!(bench.contains("  adder device_under_test(\n    .x00(x00),\n"));
+        assert!(bench.contains("    x00 = 1;\n    x01 = 0;\n    y00 = 1;\n    y01 = 1;\n"));
+        assert!(bench.contains("    #1 $display(\"%0d\", {z02, z01, z00});\n"));
+    }
+
+    #[test]
+    fn keyword_wires_are_escaped() {
+        let circuit = parse(
+            "x00: 1\ny00: 0\n\n\
+             x00 XOR y00 -> and\nand OR y00 -> z00\n",
+        )
+        .unwrap();
+        let module = verilog(&circuit, "adder");
+        assert!(module.contains("  wire \\and ;\n"));
+        assert!(module.contains("  xor gate_0(\\and , x00, y00);\n"));
+        assert!(module.contains("  or gate_1(z00, \\and , y00);\n"));
+        assert_eq!(ident("x00"), "x00");
+        assert_eq!(ident("reg"), "\\reg ");
+        assert_eq!(ident("0ab"), "\\0ab ");
+    }
+}
@@ -0,0 +1,318 @@
+use aoc_common::{debug, parse, Answer, Cursor, Params, Result, Solution};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+pub mod adder;
+pub mod export;
+pub mod verify;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Circuit;
+
+    fn parse(&self, input: &str) -> Result<Circuit> {
+        parse(input)
+    }
+
+    fn part1(&self, circuit: &Circuit, _params: &Params) -> Result<Answer> {
+        part1(circuit)
+    }
+
+    fn part2(&self, circuit: &Circuit, _params: &Params) -> Result<Answer> {
+        part2(circuit)
+    }
+}
+
+/// The initial wire values and the gates that drive the remaining wires.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    pub wires: Vec<Wire>,
+    pub gates: Vec<Gate>,
+}
+
+pub fn parse(input: &str) -> Result<Circuit> {
+    let [top, bottom] = parse::sections(input)?;
+    Ok(Circuit {
+        wires: top.lines().map(Wire::parse).collect::<Result<_>>()?,
+        gates: bottom.lines().map(Gate::parse).collect::<Result<_>>()?,
+    })
+}
+
+fn part1(circuit: &Circuit) -> Result<Answer> {
+    let values = evaluate(circuit)?;
+    let mut z_wires: Vec<(&str, bool)> = values
+        .into_iter()
+        .filter(|(w, _)| w.starts_with('z'))
+        .collect();
+    z_wires.sort();
+
+    debug!("wires: {:?}", z_wires);
+    let val = z_wires
+        .iter()
+        .rev()
+        .fold(0_u64, |n, &(_, bit)| n << 1 | u64::from(bit));
+    Ok(val.into())
+}
+
+/// The value of every wire. The gates are evaluated in topological order, so
+/// each runs once, after both of its inputs. Fails if a gate reads a wire
+/// that nothing drives, a wire is driven twice, or the gates form a cycle.
+pub fn evaluate(circuit: &Circuit) -> Result<HashMap<&str, bool>> {
+    let mut values: HashMap<&str, bool> = HashMap::new();
+    for w in &circuit.wires {
+        if values.insert(&w.name, w.value).is_some() {
+            return Err(format!("wire {} is given twice", w.name).into());
+        }
+    }
+    let mut drivers = HashMap::new();
+    for g in &circuit.gates {
+        if values.contains_key(g.output.as_str()) || drivers.insert(&g.output, g).is_some() {
+            return Err(format!("wire {} is driven twice", g.output).into());
+        }
+    }
+
+    // How many of each gate's inputs are still unknown, and which gates read
+    // each wire.
+    let mut waiting = vec![0; circuit.gates.len()];
+    let mut readers: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, g) in circuit.gates.iter().enumerate() {
+        for input in [&g.input1, &g.input2] {
+            if values.contains_key(input.as_str()) {
+                continue;
+            }
+            if !drivers.contains_key(input) {
+                return Err(format!("{} reads {}, which nothing drives", g, input).into());
+            }
+            waiting[i] += 1;
+            readers.entry(input).or_default().push(i);
+        }
+    }
+
+    let mut ready: Vec<usize> = (0..circuit.gates.len())
+        .filter(|&i| waiting[i] == 0)
+        .collect();
+    while let Some(i) = ready.pop() {
+        let g = &circuit.gates[i];
+        let (a, b) = (values[g.input1.as_str()], values[g.input2.as_str()]);
+        let value = match g.op {
+            Op::And => a & b,
+            Op::Or => a | b,
+            Op::Xor => a ^ b,
+        };
+        values.insert(&g.output, value);
+        for &r in readers.get(g.output.as_str()).into_iter().flatten() {
+            waiting[r] -= 1;
+            if waiting[r] == 0 {
+                ready.push(r);
+            }
+        }
+    }
+
+    if values.len() < circuit.wires.len() + circuit.gates.len() {
+        let mut stuck: Vec<&str> = circuit
+            .gates
+            .iter()
+            .map(|g| g.output.as_str())
+            .filter(|w| !values.contains_key(w))
+            .collect();
+        stuck.sort();
+        return Err(format!(
+            "the gates driving {} are on or behind a cycle",
+            stuck.join(", ")
+        )
+        .into());
+    }
+    Ok(values)
+}
+
+/// The wires whose gates break the pattern of a ripple-carry adder. The
+/// puzzle swaps exactly four pairs of them. `aoc24 check` explains the rule
+/// each one breaks.
+fn part2(circuit: &Circuit) -> Result<Answer> {
+    let violations = adder::check(circuit)?;
+    let swapped = adder::swapped(&violations);
+    if swapped.len() != 8 {
+        return Err(format!(
+            "expected 8 swapped wires, but {} break the adder pattern: {}",
+            swapped.len(),
+            swapped.join(",")
+        )
+        .into());
+    }
+    Ok(swapped.join(",").into())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wire {
+    pub name: String,
+    pub value: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gate {
+    pub input1: String,
+    pub input2: String,
+    pub output: String,
+    pub op: Op,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Wire {
+    /// An initial value such as `x00: 1`.
+    pub fn parse(mut line: Cursor) -> Result<Wire> {
+        let name = line.word()?.to_string();
+        line.expect(": ")?;
+        let value = match line.bump() {
+            Some('0') => false,
+            Some('1') => true,
+            _ => return Err(line.error("0 or 1").into()),
+        };
+        line.end()?;
+        Ok(Wire { name, value })
+    }
+}
+
+impl Gate {
+    /// A gate such as `x00 AND y00 -> z00`.
+    pub fn parse(mut line: Cursor) -> Result<Gate> {
+        let input1 = line.word()?.to_string();
+        line.expect(" ")?;
+        let op = Op::parse(&mut line)?;
+        line.expect(" ")?;
+        let input2 = line.word()?.to_string();
+        line.expect(" -> ")?;
+        let output = line.word()?.to_string();
+        line.end()?;
+        Ok(Gate {
+            input1,
+            input2,
+            output,
+            op,
+        })
+    }
+}
+
+impl Display for Gate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} -> {}",
+            self.input1, self.op, self.input2, self.output
+        )
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Op::And => "AND",
+            Op::Or => "OR",
+            Op::Xor => "XOR",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Op {
+    pub fn parse(line: &mut Cursor) -> Result<Op> {
+        if line.eat("AND") {
+            Ok(Op::And)
+        } else if line.eat("OR") {
+            Ok(Op::Or)
+        } else if line.eat("XOR") {
+            Ok(Op::Xor)
+        } else {
+            Err(line.error("AND, OR or XOR").into())
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A three-bit ripple-carry adder, with the carry into bit k on `ck`,
+    /// and the outputs of each pair of wires in `swaps` exchanged.
+    pub(crate) fn adder(swaps: &[(&str, &str)]) -> Circuit {
+        let mut text = String::new();
+        for bit in 0..3 {
+            text += &format!("x{:02}: 0\ny{:02}: 0\n", bit, bit);
+        }
+        text += "\nx00 XOR y00 -> z00\nx00 AND y00 -> c01\n";
+        for k in 1..3 {
+            let carry = if k == 2 {
+                "z03".to_string()
+            } else {
+                format!("c{:02}", k + 1)
+            };
+            text += &format!(
+                "x{0:02} XOR y{0:02} -> s{0:02}\n\
+                 x{0:02} AND y{0:02} -> g{0:02}\n\
+                 s{0:02} XOR c{0:02} -> z{0:02}\n\
+                 s{0:02} AND c{0:02} -> p{0:02}\n\
+                 g{0:02} OR p{0:02} -> {1}\n",
+                k, carry
+            );
+        }
+        let mut circuit = parse(&text).unwrap();
+        for (a, b) in swaps {
+            circuit.swap(a, b).unwrap();
+        }
+        circuit
+    }
+
+    fn error(input: &str) -> String {
+        evaluate(&parse(input).unwrap()).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn evaluates_in_dependency_order() {
+        // The gates are listed backwards, so each reads a wire driven later.
+        let circuit =
+            parse("x00: 1\ny00: 1\n\na OR b -> z00\nx00 XOR y00 -> b\nx00 AND y00 -> a\n").unwrap();
+        let values = evaluate(&circuit).unwrap();
+        assert_eq!(
+            (values["a"], values["b"], values["z00"]),
+            (true, false, true)
+        );
+    }
+
+    #[test]
+    fn cycles_are_errors() {
+        assert_eq!(
+            error("x00: 1\n\nx00 AND b -> a\na OR x00 -> b\na XOR x00 -> z00\n"),
+            "the gates driving a, b, z00 are on or behind a cycle"
+        );
+    }
+
+    #[test]
+    fn undriven_inputs_are_errors() {
+        assert_eq!(
+            error("x00: 1\n\nx00 AND y00 -> z00\n"),
+            "x00 AND y00 -> z00 reads y00, which nothing drives"
+        );
+    }
+
+    #[test]
+    fn wires_driven_twice_are_errors() {
+        assert_eq!(
+            error("x00: 1\ny00: 0\n\nx00 AND y00 -> z00\nx00 OR y00 -> z00\n"),
+            "wire z00 is driven twice"
+        );
+        assert_eq!(
+            error("x00: 1\ny00: 0\n\nx00 AND y00 -> y00\n"),
+            "wire y00 is driven twice"
+        );
+        assert_eq!(
+            error("x00: 1\nx00: 0\n\nx00 AND x00 -> z00\n"),
+            "wire x00 is given twice"
+        );
+    }
+}
@@ -1,134 +1,81 @@
-use std::collections::HashMap;
-use std::io::{self, Read};
-
-type Result<T> = ::std::result::Result<T, Box<dyn::std::error::Error>>;
-
+use std::env;
+
+use aoc24::verify::{self, Patterns};
+use aoc24::{adder, export};
+use aoc_common::{read, Result};
+
+/// `aoc24 check [FILE]` explains how each gate breaks the adder pattern.
+/// `aoc24 dot [FILE]` prints the circuit as a Graphviz graph and
+/// `aoc24 verilog [FILE]` as a Verilog module with a testbench.
+/// `aoc24 verify [--random N] FILE [A,B ...]` checks that the circuit adds,
+/// after swapping the outputs of each pair of wires given. Anything else
+/// solves the puzzle.
 fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-
-    part1(&input)?;
-    // part2(&input)?;
-
-    Ok(())
-}
-
-fn part1(input: &str) -> Result<()> {
-    let (top, bottom) = input.trim().split_once("\n\n").unwrap();
-
-    let mut circuit = HashMap::new();
-    let mut wire_gates = HashMap::new();
-    let wires: Vec<Wire> = top
-        .trim()
-        .lines()
-        .map(|l| {
-            let (name, value) = l.split_once(": ").unwrap();
-            let w = Wire {
-                name: name.to_string(),
-                value: value.parse().unwrap(),
-            };
-            circuit.insert(w.name.clone(), w.clone());
-            w
-        })
-        .collect();
-
-    let gates: Vec<Gate> = bottom
-        .trim()
-        .lines()
-        .map(|l| {
-            let (input, output) = l.split_once(" -> ").unwrap();
-            let inputs = input.split(" ").collect::<Vec<_>>();
-            let g = Gate {
-                input1: inputs[0].to_string(),
-                op: inputs[1].into(),
-                input2: inputs[2].to_string(),
-                output: output.to_string(),
-            };
-            wire_gates.insert(g.output.clone(), g.clone());
-            g
-        })
-        .collect();
-
-    let mut z_wires = wire_gates
-        .keys()
-        .filter(|w| w.starts_with("z"))
-        .collect::<Vec<_>>();
-    z_wires.sort();
-
-    println!("wires: {:?}", z_wires);
-    // let mut outputs = HashMap::new();
-    let mut bit_str = String::new();
-    for wire in z_wires {
-        let bit = get_value(wire.as_str(), &mut circuit, &wire_gates);
-        bit_str = format!("{}{}", bit, bit_str);
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("check") => {
+            let circuit = aoc24::parse(&read(args.get(1))?)?;
+            let violations = adder::check(&circuit)?;
+            for v in &violations {
+                println!("{}", v);
+            }
+            println!("swapped: {}", adder::swapped(&violations).join(","));
+            Ok(())
+        }
+        Some("dot") => {
+            let circuit = aoc24::parse(&read(args.get(1))?)?;
+            print!("{}", export::dot(&circuit));
+            Ok(())
+        }
+        Some("verilog") => {
+            let circuit = aoc24::parse(&read(args.get(1))?)?;
+            print!("{}", export::verilog(&circuit, "circuit"));
+            println!();
+            print!("{}", export::testbench(&circuit, "circuit"));
+            Ok(())
+        }
+        Some("verify") => verify(&args[1..]),
+        _ => aoc_common::run(&aoc24::Day24),
     }
-
-    println!("bit_str: {}", bit_str);
-    let val = usize::from_str_radix(bit_str.as_str(), 2).unwrap();
-    println!("val: {}", val);
-
-    Ok(())
 }
 
-fn get_value(
-    name: &str,
-    circuit: &mut HashMap<String, Wire>,
-    wire_gates: &HashMap<String, Gate>,
-) -> u16 {
-    if circuit.contains_key(name) {
-        return circuit.get(name).unwrap().value;
+/// Checks every per-bit pattern, then `N` random ones (100 by default).
+fn verify(args: &[String]) -> Result<()> {
+    let mut random = 100;
+    let mut file = None;
+    let mut swaps = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--random" => random = args.next().ok_or("--random needs a count")?.parse()?,
+            _ if file.is_none() => file = Some(arg),
+            _ => swaps.push(arg),
+        }
     }
 
-    let gate = wire_gates.get(name).unwrap();
-
-    let val1 = get_value(gate.input1.as_str(), circuit, wire_gates);
-    let val2 = get_value(gate.input2.as_str(), circuit, wire_gates);
-
-    let val = match gate.op {
-        Op::And => val1 & val2,
-        Op::Or => val1 | val2,
-        Op::Xor => val1 ^ val2,
-    };
+    let mut circuit = aoc24::parse(&read(Some(file.ok_or("usage: aoc24 verify FILE")?))?)?;
+    for pair in swaps {
+        let (a, b) = pair
+            .split_once(',')
+            .ok_or_else(|| format!("expected a pair of wires like a,b, found `{}`", pair))?;
+        circuit.swap(a, b)?;
+    }
 
-    circuit.insert(
-        name.to_string(),
-        Wire {
-            name: name.to_string(),
-            value: val,
+    for patterns in [
+        Patterns::PerBit,
+        Patterns::Random {
+            count: random,
+            seed: 24,
         },
-    );
-
-    val
-}
-
-#[derive(Debug, Clone)]
-struct Wire {
-    name: String,
-    value: u16,
-}
-
-#[derive(Debug, Clone)]
-struct Gate {
-    input1: String,
-    input2: String,
-    output: String,
-    op: Op,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Op {
-    And,
-    Or,
-    Xor,
-}
-
-impl From<&str> for Op {
-    fn from(s: &str) -> Self {
-        match s {
-            "AND" => Op::And,
-            "OR" => Op::Or,
-            "XOR" => Op::Xor,
-            _ => panic!("Unknown op: {}", s),
+    ] {
+        if let Some(failure) = verify::verify(&circuit, patterns)? {
+            println!("{}", failure);
+            return Ok(());
         }
     }
+    println!(
+        "adds correctly for every per-bit pattern and {} random ones",
+        random
+    );
+    Ok(())
 }
@@ -0,0 +1,240 @@
+//! Checks that a circuit adds, by running it on chosen x and y values and
+//! comparing the z wires with the sum.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{self, Display};
+
+use aoc_common::Result;
+
+use crate::{evaluate, Circuit, Gate, Wire};
+
+/// Which inputs to try.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Patterns {
+    /// For each bit, every combination of it and the bit below in x and y,
+    /// with all other bits clear. That covers each full adder with both
+    /// carry-in values.
+    PerBit,
+    /// Random x and y values.
+    Random { count: usize, seed: u64 },
+}
+
+/// The lowest bit of z that came out wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub bit: usize,
+    pub x: u64,
+    pub y: u64,
+    pub z: u64,
+    /// The gates that feed z at `bit` but not the z below it, which is where
+    /// the fault should be.
+    pub gates: Vec<Gate>,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "bit {} is wrong: {} + {} gave {}, not {}",
+            self.bit,
+            self.x,
+            self.y,
+            self.z,
+            self.x + self.y
+        )?;
+        write!(f, "it is fed by")?;
+        for g in &self.gates {
+            write!(f, "\n  {}", g)?;
+        }
+        Ok(())
+    }
+}
+
+impl Circuit {
+    /// How many bits x and y have.
+    pub fn input_bits(&self) -> usize {
+        self.wires
+            .iter()
+            .filter(|w| w.name.starts_with('x'))
+            .count()
+    }
+
+    /// The same gates, with x and y set to the given values.
+    pub fn with_inputs(&self, x: u64, y: u64) -> Circuit {
+        let bits = self.input_bits();
+        let mut wires = vec![];
+        for (name, value) in [('x', x), ('y', y)] {
+            for bit in 0..bits {
+                wires.push(Wire {
+                    name: format!("{}{:02}", name, bit),
+                    value: value >> bit & 1 == 1,
+                });
+            }
+        }
+        Circuit {
+            wires,
+            gates: self.gates.clone(),
+        }
+    }
+
+    /// Exchanges the output wires of the gates driving `a` and `b`.
+    pub fn swap(&mut self, a: &str, b: &str) -> Result<()> {
+        let find = |name: &str| {
+            self.gates
+                .iter()
+                .position(|g| g.output == name)
+                .ok_or_else(|| format!("no gate drives {}", name))
+        };
+        let (i, j) = (find(a)?, find(b)?);
+        self.gates[i].output = b.to_string();
+        self.gates[j].output = a.to_string();
+        Ok(())
+    }
+}
+
+/// The lowest failing bit over all the `patterns`, or `None` if the circuit
+/// adds them all correctly.
+pub fn verify(circuit: &Circuit, patterns: Patterns) -> Result<Option<Failure>> {
+    let bits = circuit.input_bits();
+    if bits == 0 || bits > 63 {
+        return Err(format!("cannot add {}-bit numbers", bits).into());
+    }
+    let mask = (1 << bits) - 1;
+
+    let inputs: Vec<(u64, u64)> = match patterns {
+        Patterns::PerBit => (0..bits)
+            .flat_map(|bit| {
+                // The low two bits of each value go to the bit and the one
+                // below it, which produces the carry in.
+                let place = move |v: u64| {
+                    let below = if bit > 0 {
+                        (v >> 1 & 1) << (bit - 1)
+                    } else {
+                        0
+                    };
+                    (v & 1) << bit | below
+                };
+                (0..16).map(move |n: u64| (place(n & 3), place(n >> 2)))
+            })
+            .map(|(x, y)| (x & mask, y & mask))
+            .collect(),
+        Patterns::Random { count, seed } => {
+            let mut rng = XorShift(seed | 1);
+            (0..count)
+                .map(|_| (rng.next() & mask, rng.next() & mask))
+                .collect()
+        }
+    };
+
+    let mut lowest: Option<(usize, u64, u64, u64)> = None;
+    for (x, y) in inputs {
+        let z = output(&circuit.with_inputs(x, y))?;
+        let wrong = z ^ (x + y);
+        if wrong == 0 {
+            continue;
+        }
+        let bit = wrong.trailing_zeros() as usize;
+        if lowest.is_none_or(|(b, ..)| bit < b) {
+            lowest = Some((bit, x, y, z));
+        }
+    }
+
+    Ok(lowest.map(|(bit, x, y, z)| Failure {
+        bit,
+        x,
+        y,
+        z,
+        gates: new_gates(circuit, bit),
+    }))
+}
+
+/// The number on the z wires.
+fn output(circuit: &Circuit) -> Result<u64> {
+    let values = evaluate(circuit)?;
+    Ok(values
+        .iter()
+        .filter_map(|(w, &v)| Some((w.strip_prefix('z')?.parse::<u32>().ok()?, v)))
+        .fold(0, |z, (bit, v)| z | u64::from(v) << bit))
+}
+
+/// The gates in the fan-in of z at `bit` that are not in the fan-in of the
+/// z below it.
+fn new_gates(circuit: &Circuit, bit: usize) -> Vec<Gate> {
+    let drivers: HashMap<&str, &Gate> = circuit
+        .gates
+        .iter()
+        .map(|g| (g.output.as_str(), g))
+        .collect();
+    let fan_in = |z: String| {
+        let mut seen = BTreeSet::new();
+        let mut todo = vec![z];
+        while let Some(wire) = todo.pop() {
+            if let Some(g) = drivers.get(wire.as_str()) {
+                if seen.insert(g.output.clone()) {
+                    todo.push(g.input1.clone());
+                    todo.push(g.input2.clone());
+                }
+            }
+        }
+        seen
+    };
+
+    let below = match bit {
+        0 => BTreeSet::new(),
+        _ => fan_in(format!("z{:02}", bit - 1)),
+    };
+    fan_in(format!("z{:02}", bit))
+        .difference(&below)
+        .map(|w| drivers[w.as_str()].clone())
+        .collect()
+}
+
+/// A small, seedable pseudo-random generator, good enough for test inputs.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::adder;
+
+    const RANDOM: Patterns = Patterns::Random {
+        count: 50,
+        seed: 24,
+    };
+
+    #[test]
+    fn a_correct_adder_passes() {
+        let circuit = adder(&[]);
+        assert_eq!(circuit.input_bits(), 3);
+        assert_eq!(verify(&circuit, Patterns::PerBit).unwrap(), None);
+        assert_eq!(verify(&circuit, RANDOM).unwrap(), None);
+    }
+
+    #[test]
+    fn a_swap_fails_at_its_bit() {
+        let circuit = adder(&[("z01", "c02")]);
+        for patterns in [Patterns::PerBit, RANDOM] {
+            let failure = verify(&circuit, patterns).unwrap().unwrap();
+            assert_eq!(failure.bit, 1);
+            assert_ne!(failure.z, failure.x + failure.y);
+            let wires: Vec<&str> = failure.gates.iter().map(|g| g.output.as_str()).collect();
+            assert_eq!(wires, ["c01", "g01", "p01", "s01", "z01"]);
+        }
+    }
+
+    #[test]
+    fn swapping_back_fixes_it() {
+        let mut circuit = adder(&[("z01", "c02")]);
+        circuit.swap("z01", "c02").unwrap();
+        assert_eq!(verify(&circuit, Patterns::PerBit).unwrap(), None);
+    }
+}
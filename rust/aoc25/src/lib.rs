@@ -0,0 +1,59 @@
+use aoc_common::{parse, Answer, Grid, Params, Result, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Grid<char>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Grid<char>>> {
+        parse(input)
+    }
+
+    fn part1(&self, schematics: &Vec<Grid<char>>, _params: &Params) -> Result<Answer> {
+        part1(schematics)
+    }
+}
+
+/// The lock and key schematics, one grid per blank-line-separated block.
+pub fn parse(input: &str) -> Result<Vec<Grid<char>>> {
+    parse::blocks(input).into_iter().map(Grid::parse).collect()
+}
+
+fn part1(schematics: &[Grid<char>]) -> Result<Answer> {
+    let mut locks = vec![];
+    let mut keys = vec![];
+    for grid in schematics {
+        // println!("{:?}", grid);
+        // locks have their top row filled, keys their bottom row; skip that row
+        let is_key = grid.row(0).iter().all(|c| *c == '.');
+        let skip = if is_key { grid.rows() - 1 } else { 0 };
+        let mut heights = vec![0; grid.cols()];
+        for p in grid.positions(|c| *c == '#') {
+            if p.row as usize != skip {
+                heights[p.col as usize] += 1;
+            }
+        }
+
+        if is_key {
+            keys.push(heights);
+        } else {
+            locks.push(heights);
+        }
+    }
+
+    // println!("{:?}", locks);
+    // println!("{:?}", keys);
+
+    let mut unique_locks = 0;
+    for lock in locks {
+        for key in &keys {
+            // println!("{:?} {:?}", lock, key);
+            let overlap = lock.iter().zip(key).any(|(l, k)| l + k >= 6);
+            if !overlap {
+                unique_locks += 1;
+            }
+        }
+    }
+
+    Ok(unique_locks.into())
+}